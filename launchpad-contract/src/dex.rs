@@ -1,13 +1,12 @@
 use concordium_cis2::{
-    IsTokenAmount, IsTokenId, TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdU8, TokenIdVec,
-    TransferParams,
+    TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdVec, Transfer, TransferParams,
 };
 use concordium_std::{
-    Address, Amount, CallContractError, ContractAddress, Deserial, EntrypointName, HasHost, Host,
-    SchemaType, Serial, Serialize,
+    ensure, Address, Amount, CallContractError, ContractAddress, Deserial, EntrypointName, HasHost,
+    Host, SchemaType, Serial, Serialize, Vec,
 };
 
-use crate::{errors::Error, state::State};
+use crate::{errors::Error, params::WithdrawParams, state::State};
 
 /// DEX `getExchange` entry-point name as `EntrypointName` type.
 const GET_EXCHANGE_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_unchecked("getExchange");
@@ -15,6 +14,11 @@ const GET_EXCHANGE_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_uncheck
 const ADD_LIQUIDITY_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_unchecked("addLiquidity");
 /// DEX `transfer` entry-point name as `EntrypointName` type.
 const TRANSFER_ENTRYPOINT_NAME: EntrypointName = EntrypointName::new_unchecked("transfer");
+/// Basis points in a whole, used for the price deviation of the pool.
+const BASIS_POINTS: u64 = 10000;
+/// Reject code of the DEX `ExchangeNotFound` error, raised when there is
+/// no pool for the token.
+const EXCHANGE_NOT_FOUND: i32 = -2;
 
 /// Defines the parameters to be passed required for adding liquidity
 /// in DEX.
@@ -65,10 +69,119 @@ pub struct ExchangeView {
     pub lp_tokens_holder_balance: TokenAmount,
 }
 
+impl ExchangeView {
+    /// Checks if the price of the token in the pool deviates from the given
+    /// price by more than the given maximum deviation in basis points.
+    ///
+    /// Empty pool, without any price, never deviates.
+    pub fn deviates_from(&self, price: Amount, max_deviation: u64) -> bool {
+        if self.token_balance.0 == 0 {
+            return false;
+        }
+
+        // Comparing the CCD balance of the pool with the value of its token
+        // balance at the given price, scaled to basis points
+        let pool_value = u128::from(self.ccd_balance.0) * u128::from(BASIS_POINTS);
+        let token_value = u128::from(self.token_balance.0) * u128::from(price.micro_ccd);
+        let max_value = token_value * u128::from(BASIS_POINTS.saturating_add(max_deviation));
+        let min_value = token_value * u128::from(BASIS_POINTS.saturating_sub(max_deviation));
+
+        pool_value > max_value || pool_value < min_value
+    }
+}
+
+/// Alias for the LPTokens transfer to be made by a liquidity venue
+pub type LpTransfer = Transfer<TokenIdU64, TokenAmount>;
+
+/// # Liquidity Venue
+///
+/// Defines the interface of a venue, such as a DEX contract, where the launch
+/// pad locks the liquidity of the product tokens against the raised CCD, and
+/// holds the LPTokens received in exchange.
+///
+/// It is generic over the host `H`, so that a venue can be implemented either
+/// on top of the contract host or in-process without invoking any contract.
+pub trait LiquidityVenue<H> {
+    /// Contract address of the venue, which is made operator of the
+    /// product tokens before adding the liquidity.
+    fn address(&self) -> ContractAddress;
+
+    /// Finds the liquidity pool of the token from the perspective of the
+    /// given holder of LPTokens.
+    ///
+    /// Returns `None` if there is no pool for the token yet.
+    fn find_pool(
+        &self,
+        host: &mut H,
+        holder: Address,
+        token: &TokenInfo,
+    ) -> Result<Option<ExchangeView>, Error>;
+
+    /// Adds the liquidity of the token against the CCD amount on behalf of
+    /// the provider, which receives the LPTokens in exchange.
+    fn add_liquidity(
+        &mut self,
+        host: &mut H,
+        provider: Address,
+        params: &AddLiquidityParams,
+        amount: Amount,
+    ) -> Result<(), Error>;
+
+    /// Transfers the LPTokens held by the launch pad in the venue.
+    fn transfer_lp_tokens(&mut self, host: &mut H, transfers: Vec<LpTransfer>)
+        -> Result<(), Error>;
+}
+
+/// Adds the liquidity of the token to the venue within the bounds provided
+/// by the product owner.
+///
+/// Liquidity is only added to an existing pool if its price is close enough
+/// to the liquidity price of the product token, so that the pool can not be skewed
+/// ahead of the withdrawal. LPTokens received are computed from the balance
+/// of the provider before and after adding the liquidity, since the provider
+/// may already hold LPTokens of the pool on behalf of other launch pads.
+///
+/// Returns the LPToken ID of the pool and the amount of LPTokens received.
+pub fn add_bounded_liquidity<H, V: LiquidityVenue<H>>(
+    venue: &mut V,
+    host: &mut H,
+    provider: Address,
+    params: &AddLiquidityParams,
+    amount: Amount,
+    price: Amount,
+    bounds: &WithdrawParams,
+) -> Result<(TokenIdU64, TokenAmount), Error> {
+    let pool = venue.find_pool(host, provider, &params.token)?;
+    if let Some(pool) = &pool {
+        ensure!(
+            !pool.deviates_from(price, bounds.max_price_deviation),
+            Error::PriceDeviation
+        );
+    }
+    let lp_balance = pool.map_or(0.into(), |pool| pool.lp_tokens_holder_balance);
+
+    venue.add_liquidity(host, provider, params, amount)?;
+
+    let exchange = venue
+        .find_pool(host, provider, &params.token)?
+        .ok_or(Error::JobFailed)?;
+
+    let lp_received: TokenAmount = exchange
+        .lp_tokens_holder_balance
+        .0
+        .saturating_sub(lp_balance.0)
+        .into();
+    ensure!(lp_received >= bounds.min_lp_tokens, Error::Slippage);
+
+    Ok((exchange.lp_token_id, lp_received))
+}
+
 /// # DEX Client
 ///
 /// This is the client implementation for `DEX(Decentralized-Exchange)` contract, which
 /// provides APIs to let the launch-pad interact with DEX compliant contract.
+///
+/// It is the `pixpel_swap` implementation of the `LiquidityVenue`.
 pub struct DexClient(pub ContractAddress);
 
 impl DexClient {
@@ -78,12 +191,6 @@ impl DexClient {
         Self(contract_address)
     }
 
-    /// Getter method to get the dex contract address of the DEX
-    /// client.
-    pub fn address(&self) -> ContractAddress {
-        self.0
-    }
-
     /// Calls the `getExchange` entry point of the DEX contract
     /// and returns the result.
     ///
@@ -104,53 +211,6 @@ impl DexClient {
         Ok(result.1.unwrap())
     }
 
-    /// Calls the `addLiquidity` entry point of the DEX contract
-    /// to add a liquidity pool and returns the result.
-    ///
-    /// Returns never type `()` or the error returned by DEX client.
-    pub fn add_liquidity(
-        &self,
-        host: &mut Host<State>,
-        token_id: TokenIdU8,
-        token_amount: TokenAmount,
-        amount: Amount,
-        cis2_contract: ContractAddress,
-    ) -> Result<(), Error> {
-        self.invoke_contract::<_, ()>(
-            host,
-            &AddLiquidityParams {
-                token: TokenInfo {
-                    id: TokenIdVec(token_id.0.to_ne_bytes().into()),
-                    address: cis2_contract,
-                },
-                token_amount,
-            },
-            ADD_LIQUIDITY_ENTRYPOINT_NAME,
-            amount,
-        )?;
-
-        Ok(())
-    }
-
-    /// Calls the `transfer` entry point of the DEX contract
-    /// to transfer the LPTokens and returns the result.
-    ///
-    /// Returns a `bool` if the transfer was successful or the
-    /// error returned by DEX client.
-    pub fn transfer<T, A>(
-        &self,
-        host: &mut Host<State>,
-        params: TransferParams<T, A>,
-    ) -> Result<bool, Error>
-    where
-        T: IsTokenId,
-        A: IsTokenAmount,
-    {
-        let (state_modified, _) =
-            self.invoke_contract::<_, ()>(host, &params, TRANSFER_ENTRYPOINT_NAME, Amount::zero())?;
-        Ok(state_modified)
-    }
-
     /// Raw implementation of invoking a method in DEX client. It
     /// is generic over input parameters and the return value type.
     ///
@@ -172,7 +232,7 @@ impl DexClient {
         P: Serial,
         R: Deserial,
     {
-        let res = host.invoke_contract(&self.address(), params, method, amount);
+        let res = host.invoke_contract(&self.0, params, method, amount);
 
         let res = match res {
             Ok(val) => {
@@ -203,3 +263,65 @@ impl DexClient {
         Ok(res)
     }
 }
+
+impl LiquidityVenue<Host<State>> for DexClient {
+    /// Getter method to get the dex contract address of the DEX
+    /// client.
+    fn address(&self) -> ContractAddress {
+        self.0
+    }
+
+    /// Calls the `getExchange` entry point of the DEX contract to
+    /// find the liquidity pool of the token.
+    ///
+    /// DEX rejects with `ExchangeNotFound` if there is no pool for the
+    /// token, which results in `None`. Any other rejection is propagated.
+    fn find_pool(
+        &self,
+        host: &mut Host<State>,
+        holder: Address,
+        token: &TokenInfo,
+    ) -> Result<Option<ExchangeView>, Error> {
+        let params = GetExchangeParams {
+            holder,
+            token: token.clone(),
+        };
+
+        match self.get_exchange(host, &params) {
+            Ok(exchange) => Ok(Some(exchange)),
+            Err(Error::DEX(EXCHANGE_NOT_FOUND)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Calls the `addLiquidity` entry point of the DEX contract, which
+    /// issues the LPTokens to the invoker as the provider.
+    fn add_liquidity(
+        &mut self,
+        host: &mut Host<State>,
+        _provider: Address,
+        params: &AddLiquidityParams,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        self.invoke_contract::<_, ()>(host, params, ADD_LIQUIDITY_ENTRYPOINT_NAME, amount)?;
+
+        Ok(())
+    }
+
+    /// Calls the `transfer` entry point of the DEX contract
+    /// to transfer the LPTokens.
+    fn transfer_lp_tokens(
+        &mut self,
+        host: &mut Host<State>,
+        transfers: Vec<LpTransfer>,
+    ) -> Result<(), Error> {
+        self.invoke_contract::<_, ()>(
+            host,
+            &TransferParams(transfers),
+            TRANSFER_ENTRYPOINT_NAME,
+            Amount::zero(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use concordium_cis2::{Cis2ClientError, Cis2Error};
 use concordium_std::{
-    CallContractError, LogError, ParseError, Reject, SchemaType, Serialize, TransferError,
-    UnwrapAbort,
+    CallContractError, ExternCallResponse, LogError, ParseError, Reject, SchemaType, Serialize,
+    TransferError, UnwrapAbort, UpgradeError,
 };
 
 pub mod num {
@@ -14,6 +14,8 @@ use concordium_std::from_bytes;
 /// This defines all the errors as reject reason, occured while any underlying
 /// methods invocation.
 #[derive(Serialize, Debug, PartialEq, Reject, Eq, SchemaType)]
+// `CIS2` and `DEX` name the contracts the reject reasons come from
+#[allow(clippy::upper_case_acronyms)]
 pub enum Error {
     /// Raised when parsing the parameter failed.
     ///
@@ -139,6 +141,75 @@ pub enum Error {
     ///
     /// Code -24
     DEX(i32),
+    /// Raised when an investment exceeds the hard cap of
+    /// the launch pad.
+    ///
+    /// Code -25
+    HardCap,
+    /// Raised when the module to upgrade to does not exist.
+    ///
+    /// Code -26
+    FailedUpgradeMissingModule,
+    /// Raised when the module to upgrade to does not contain
+    /// the launch pad contract.
+    ///
+    /// Code -27
+    FailedUpgradeMissingContract,
+    /// Raised when the module to upgrade to is a version 0
+    /// smart contract module.
+    ///
+    /// Code -28
+    FailedUpgradeUnsupportedModuleVersion,
+    /// Raised when the migration invoked after upgrade is
+    /// rejected with the given reject reason.
+    ///
+    /// Code -29
+    Migration(i32),
+    /// Raised when an operation is invoked while the platform
+    /// is paused by the admin.
+    ///
+    /// Code -30
+    Paused,
+    /// Raised when the product tokens of a forcibly canceled
+    /// launch pad are reclaimed while frozen for review.
+    ///
+    /// Code -31
+    Frozen,
+    /// Raised when the holder claims the release cycles while
+    /// the launch pad is still in its cliff period.
+    ///
+    /// Code -32
+    Cliff,
+    /// Raised when the LPTokens received for the liquidity added
+    /// to the DEX are less than the minimum expected.
+    ///
+    /// Code -33
+    Slippage,
+    /// Raised when the price of the existing liquidity pool deviates
+    /// from the base price of the product token beyond the bound.
+    ///
+    /// Code -34
+    PriceDeviation,
+    /// Raised when the DEX contract is neither the platform DEX nor
+    /// one of the DEX contracts registered by the admin.
+    ///
+    /// Code -35
+    UnknownDex,
+    /// Raised when the product tokens are deposited for a launch
+    /// pad which is not approved.
+    ///
+    /// Code -36
+    NotApproved,
+    /// Raised when the CIS2 contract of the product does not
+    /// report support for the CIS2 standard through CIS-0.
+    ///
+    /// Code -37
+    NotCis2,
+    /// Raised when a launch pad paid in a CIS2 token allocates funds
+    /// for liquidity, since the DEX only pairs the tokens with CCD.
+    ///
+    /// Code -38
+    TokenLiquidity,
 }
 
 // Mapping error received while transfering amount `(TransferError)`
@@ -193,6 +264,37 @@ impl From<CallContractError<Cis2Error<Error>>> for Error {
     }
 }
 
+// Mapping error received while upgrading the contract module `(UpgradeError)`
+// to the contract error.
+impl From<UpgradeError> for Error {
+    fn from(value: UpgradeError) -> Self {
+        match value {
+            UpgradeError::MissingModule => Self::FailedUpgradeMissingModule,
+            UpgradeError::MissingContract => Self::FailedUpgradeMissingContract,
+            UpgradeError::UnsupportedModuleVersion => Self::FailedUpgradeUnsupportedModuleVersion,
+        }
+    }
+}
+
+// Mapping error received while invoking the migration after upgrade to the
+// contract error.
+impl From<CallContractError<ExternCallResponse>> for Error {
+    fn from(e: CallContractError<ExternCallResponse>) -> Self {
+        match e {
+            CallContractError::AmountTooLarge => Self::AmountTooLarge,
+            CallContractError::MissingAccount => Self::MissingAccount,
+            CallContractError::MissingContract => Self::MissingContract,
+            CallContractError::MissingEntrypoint => Self::MissingEntrypoint,
+            CallContractError::MessageFailed => Self::MessageFailed,
+            CallContractError::LogicReject {
+                reason,
+                return_value: _,
+            } => Self::Migration(reason),
+            CallContractError::Trap => Self::Trap,
+        }
+    }
+}
+
 #[cfg(test)]
 use concordium_smart_contract_testing::{
     ContractInvokeError, ContractInvokeErrorKind, InvokeFailure,
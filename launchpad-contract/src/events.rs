@@ -1,121 +1,291 @@
-use std::collections::BTreeMap;
+use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU64};
+use concordium_std::{
+    schema, AccountAddress, Amount, ContractAddress, SchemaType, Serialize, Timestamp,
+};
 
-use concordium_cis2::TokenAmountU64 as TokenAmount;
-use concordium_std::{schema, AccountAddress, Amount, Serialize, Timestamp};
+use crate::{
+    state::{Role, TimePeriod, VestingLimits},
+    LaunchPadId,
+};
 
-use crate::state::{TimePeriod, VestingLimits};
+/// Gets the fields of the given event struct, to be flattened in the
+/// `Event` schema.
+fn event_fields<T: schema::SchemaType>() -> schema::Fields {
+    match T::get_type() {
+        schema::Type::Struct(fields) => fields,
+        _ => schema::Fields::None,
+    }
+}
 
-/// Tagged Launch-pad events to be serialized for the event logging.
+/// Defines the `Event` enum along with its schema, so that the schema is
+/// generated from the event structs and always follows the order of the
+/// variants.
 ///
-/// Each event represent a major state change in contract or launch-pad
-#[derive(Serialize)]
-pub enum Event {
+/// Deriving the schemaType would result in e.g.: {"AddItemEvent": [{...fields}] }.
+/// In contrast, the generated schema flattens the fields to avoid one level of
+/// nesting and results in e.g.: {"AddItemEvent": {...fields} }
+macro_rules! events {
+    ($($(#[doc = $doc:literal])* $variant:ident($event:ident) as $name:literal,)*) => {
+        /// Tagged Launch-pad events to be serialized for the event logging.
+        ///
+        /// Each event represent a major state change in contract or launch-pad
+        #[derive(Serialize)]
+        #[allow(clippy::upper_case_acronyms)]
+        pub enum Event {
+            $($(#[doc = $doc])* $variant($event),)*
+        }
+
+        impl schema::SchemaType for Event {
+            fn get_type() -> schema::Type {
+                let events = vec![$(($name.to_string(), event_fields::<$event>()),)*];
+
+                schema::Type::TaggedEnum(
+                    events
+                        .into_iter()
+                        .enumerate()
+                        .map(|(tag, event)| (tag as u8, event))
+                        .collect(),
+                )
+            }
+        }
+    };
+}
+
+events! {
     /// Event to be logged when a new launch pad is just created
-    CREATED(CreateLaunchPadEvent),
+    CREATED(CreateLaunchPadEvent) as "CreateLaunchPadEvent",
     /// Event to be logged when a new launch pad is approved for
     /// presale
-    APPROVED(ApproveEvent),
+    APPROVED(ApproveEvent) as "ApproveEvent",
     /// Event to be logged when a new launch pad is rejected for
     /// presale
-    REJECTED(RejectEvent),
+    REJECTED(RejectEvent) as "RejectEvent",
     /// Event to be logged when a launch pad is ready for vesting
     /// after token allocation
-    VESTINGSTARTED(VestEvent),
+    VESTINGSTARTED(VestEvent) as "VestEvent",
     /// Event to be logged when a launch pad finishes vesting and
     /// enters the cliff period
-    CLIFFSTARTED(CliffEvent),
-}
-
-// Implementing a custom schemaType for the `Event` struct.
-// This custom implementation flattens the fields to avoid one
-// level of nesting. Deriving the schemaType would result in e.g.:
-// {"AddItemEvent": [{...fields}] }. In contrast, this custom schemaType
-// implementation results in e.g.: {"AddItemEvent": {...fields} }
-impl schema::SchemaType for Event {
-    fn get_type() -> schema::Type {
-        let mut event_map = BTreeMap::new();
-        let events = vec![
-            (
-                "CreateLaunchPadEvent".to_string(),
-                schema::Fields::Named(vec![
-                    (String::from("launchpad_id"), u16::get_type()),
-                    (String::from("launchpad_name"), String::get_type()),
-                    (String::from("owner"), AccountAddress::get_type()),
-                    (String::from("allocated_tokens"), TokenAmount::get_type()),
-                    (String::from("base_price"), Amount::get_type()),
-                ]),
-            ),
-            (
-                "ApproveEvent".to_string(),
-                schema::Fields::Named(vec![
-                    (String::from("launchpad_id"), u16::get_type()),
-                    (String::from("launchpad_name"), String::get_type()),
-                ]),
-            ),
-            (
-                "RejectEvent".to_string(),
-                schema::Fields::Named(vec![
-                    (String::from("launchpad_id"), u16::get_type()),
-                    (String::from("launchpad_name"), String::get_type()),
-                ]),
-            ),
-            (
-                "CliffEvent".to_string(),
-                schema::Fields::Named(vec![
-                    (String::from("launchpad_id"), u16::get_type()),
-                    (String::from("launchpad_name"), String::get_type()),
-                    (String::from("from"), Timestamp::get_type()),
-                    (String::from("to"), Timestamp::get_type()),
-                ]),
-            ),
-            (
-                "VestEvent".to_string(),
-                schema::Fields::Named(vec![
-                    (String::from("launchpad_id"), u16::get_type()),
-                    (String::from("launchpad_name"), String::get_type()),
-                    (String::from("vesting_time"), TimePeriod::get_type()),
-                    (String::from("vesting_limits"), VestingLimits::get_type()),
-                ]),
-            ),
-        ];
-
-        for (key, value) in events.iter().enumerate() {
-            event_map.insert(key as u8, value.clone());
-        }
-
-        schema::Type::TaggedEnum(event_map)
-    }
+    CLIFFSTARTED(CliffEvent) as "CliffEvent",
+    /// Event to be logged when a holder gets the invested amount
+    /// refunded from a canceled or failed launch pad
+    REFUNDED(RefundEvent) as "RefundEvent",
+    /// Event to be logged when the product owner reclaims the tokens
+    /// which are left unsold or not required anymore
+    RECLAIMED(ReclaimEvent) as "ReclaimEvent",
+    /// Event to be logged when any of the admin details is updated
+    ADMINUPDATED(AdminUpdateEvent) as "AdminUpdateEvent",
+    /// Event to be logged when the admin proposes a new account to
+    /// take over the admin role
+    ADMINPROPOSED(AdminProposeEvent) as "AdminProposeEvent",
+    /// Event to be logged when a role is granted to an account
+    ROLEGRANTED(RoleEvent) as "RoleGrantEvent",
+    /// Event to be logged when a role is revoked from an account
+    ROLEREVOKED(RoleEvent) as "RoleRevokeEvent",
+    /// Event to be logged when the platform is paused in case of
+    /// emergency
+    PLATFORMPAUSED(PlatformPauseEvent) as "PlatformPauseEvent",
+    /// Event to be logged when the platform is resumed after the
+    /// emergency pause
+    PLATFORMRESUMED(PlatformResumeEvent) as "PlatformResumeEvent",
+    /// Event to be logged when a holder invests in a launch pad
+    INVESTED(InvestEvent) as "InvestEvent",
+    /// Event to be logged when the product owner pauses a launch pad
+    PAUSED(PauseEvent) as "PauseEvent",
+    /// Event to be logged when the product owner resumes a paused
+    /// launch pad
+    RESUMED(ResumeEvent) as "ResumeEvent",
+    /// Event to be logged when a holder claims the product tokens of
+    /// a release cycle
+    CLAIMED(ClaimEvent) as "ClaimEvent",
+    /// Event to be logged when the owner or a holder claims the locked
+    /// LPTokens of a release cycle
+    LOCKEDCLAIMED(LockedClaimEvent) as "LockedClaimEvent",
+    /// Event to be logged when the product owner withdraws the raised
+    /// funds
+    WITHDRAWN(WithdrawEvent) as "WithdrawEvent",
+    /// Event to be logged when the raised funds are locked in the
+    /// platform DEX liquidity
+    LIQUIDITYADDED(LiquidityEvent) as "LiquidityEvent",
+    /// Event to be logged when a launch pad is canceled by the owner
+    /// or forcibly by the admin
+    CANCELED(CancelEvent) as "CancelEvent",
+    /// Event to be logged when the product owner deposits the product
+    /// tokens, fully or partially funding the allocated tokens
+    DEPOSITED(DepositEvent) as "DepositEvent",
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, SchemaType)]
 pub struct CreateLaunchPadEvent {
+    pub launchpad_id: LaunchPadId,
     pub launchpad_name: String,
     pub owner: AccountAddress,
     pub allocated_tokens: TokenAmount,
     pub base_price: Amount,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, SchemaType)]
 pub struct ApproveEvent {
+    pub launchpad_id: LaunchPadId,
     pub launchpad_name: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, SchemaType)]
 pub struct RejectEvent {
+    pub launchpad_id: LaunchPadId,
     pub launchpad_name: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, SchemaType)]
 pub struct CliffEvent {
-    pub launchpad_id: u64,
+    pub launchpad_id: LaunchPadId,
     pub launchpad_name: String,
     pub from: Timestamp,
     pub to: Timestamp,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, SchemaType)]
+pub struct DepositEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub amount: TokenAmount,
+    pub deposited: TokenAmount,
+}
+
+#[derive(Serialize, SchemaType)]
 pub struct VestEvent {
+    pub launchpad_id: LaunchPadId,
     pub launchpad_name: String,
     pub vesting_time: TimePeriod,
     pub vesting_limits: VestingLimits,
 }
+
+#[derive(Serialize, SchemaType)]
+pub struct RefundEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub holder: AccountAddress,
+    pub amount: Amount,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct ReclaimEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub owner: AccountAddress,
+    pub amount: TokenAmount,
+}
+
+/// Defines which admin detail has been updated along with its new value
+#[derive(Serialize, SchemaType)]
+pub enum AdminUpdate {
+    /// Admin account address, after the proposed admin accepts the role
+    Address(AccountAddress),
+    /// Platform registeration fee for launch pad creation
+    RegisterationFee(Amount),
+    /// Allocation share in percentage of the product tokens
+    AllocationShare(u64),
+    /// Liquidity share in percentage of the LPTokens
+    LiquidityShare(u64),
+    /// Platform DEX contract address
+    DexAddress(ContractAddress),
+    /// DEX contract registered to lock the liquidity of launch pads
+    DexRegistered(ContractAddress),
+    /// DEX contract no longer available to new launch pads
+    DexUnregistered(ContractAddress),
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct AdminUpdateEvent {
+    pub update: AdminUpdate,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct AdminProposeEvent {
+    pub current: AccountAddress,
+    pub proposed: AccountAddress,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct RoleEvent {
+    pub account: AccountAddress,
+    pub role: Role,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct PlatformPauseEvent {
+    pub by: AccountAddress,
+    pub reason: String,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct PlatformResumeEvent {
+    pub by: AccountAddress,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct InvestEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub holder: AccountAddress,
+    pub round: u8,
+    pub token_amount: TokenAmount,
+    pub amount: Amount,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct PauseEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub pause_duration: TimePeriod,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct ResumeEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct ClaimEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub holder: AccountAddress,
+    pub cycle: u8,
+    pub amount: TokenAmount,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct LockedClaimEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub claimer: AccountAddress,
+    pub cycle: u8,
+    pub lp_token_id: TokenIdU64,
+    pub amount: TokenAmount,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct WithdrawEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub owner: AccountAddress,
+    pub amount: Amount,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct LiquidityEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub amount: Amount,
+    pub token_amount: TokenAmount,
+    pub lp_token_id: TokenIdU64,
+    pub lp_tokens: TokenAmount,
+}
+
+#[derive(Serialize, SchemaType)]
+pub struct CancelEvent {
+    pub launchpad_id: LaunchPadId,
+    pub launchpad_name: String,
+    pub by: AccountAddress,
+    pub forced: bool,
+}
@@ -1,11 +1,21 @@
-use concordium_cis2::{OperatorUpdate, UpdateOperator, UpdateOperatorParams};
+use concordium_cis2::{
+    AdditionalData, Cis2Client, OperatorUpdate, SupportResult, TokenAmountU64 as TokenAmount,
+    TokenMetadataQueryParams, TokenMetadataQueryResponse, Transfer, UpdateOperator,
+    UpdateOperatorParams,
+};
 use concordium_std::{
-    Address, Amount, CallContractError, ContractAddress, EntrypointName, HasHost, Host,
+    AccountAddress, Address, Amount, CallContractError, ContractAddress, Deserial, EntrypointName,
+    HasHost, MetadataUrl,
 };
 
-use crate::{errors::Error, state::State};
+use crate::{
+    errors::Error,
+    params::Months,
+    state::{Funds, Product},
+};
 
 const UPDATE_OPERATOR_ENTRYPOINT: EntrypointName = EntrypointName::new_unchecked("updateOperator");
+const TOKEN_METADATA_ENTRYPOINT: EntrypointName = EntrypointName::new_unchecked("tokenMetadata");
 
 /// This is the re-implementation of a method `update_operator_of` defined in Cis2Client.
 ///
@@ -15,8 +25,8 @@ const UPDATE_OPERATOR_ENTRYPOINT: EntrypointName = EntrypointName::new_unchecked
 ///
 /// It returns the result type, which contains either `bool` on successful invocation
 /// or returns `Error::CIS2(reason)`, with the actual reject code.
-pub fn update_operator_of(
-    host: &mut Host<State>,
+pub fn update_operator_of<T>(
+    host: &mut impl HasHost<T>,
     cis2_contract: ContractAddress,
     operator_to_be: Address,
 ) -> Result<bool, Error> {
@@ -54,3 +64,116 @@ pub fn update_operator_of(
 
     Ok(res)
 }
+
+/// Verifies the product token of a launch pad against its CIS2 contract.
+///
+/// The contract must support CIS2 through the CIS-0 `supports` query, and
+/// the product owner must hold at least the allocated tokens. On success, it
+/// returns the metadata URL of the token reported by the contract.
+pub fn verify_product_token<T>(
+    host: &impl HasHost<T>,
+    product: &Product,
+) -> Result<MetadataUrl, Error> {
+    let client = Cis2Client::new(product.cis2_contract);
+
+    // Contracts not implementing the CIS-0 `supports` query are not
+    // considered CIS2 compliant either
+    match client.supports_cis2::<T, Error>(host) {
+        Ok(SupportResult::Support) => (),
+        _ => return Err(Error::NotCis2),
+    }
+
+    let balance: TokenAmount = client.balance_of::<T, _, _, Error>(
+        host,
+        product.token_id.clone(),
+        product.owner.into(),
+    )?;
+    if balance < product.allocated_tokens {
+        return Err(Error::Insufficient);
+    }
+
+    // Contracts rejecting the `tokenMetadata` query of the token are not
+    // considered CIS2 compliant either
+    let mut res = host
+        .invoke_contract_read_only(
+            &product.cis2_contract,
+            &TokenMetadataQueryParams {
+                queries: vec![product.token_id.clone()],
+            },
+            TOKEN_METADATA_ENTRYPOINT,
+            Amount::zero(),
+        )
+        .map_err(|_| Error::NotCis2)?
+        .ok_or(Error::InvalidResponse)?;
+    let TokenMetadataQueryResponse(mut urls) = TokenMetadataQueryResponse::deserial(&mut res)?;
+
+    urls.pop().ok_or(Error::InvalidResponse)
+}
+
+/// Transfers the funds of a launch pad to the given account, either in CCD
+/// or in the CIS2 payment token accepted by the launch pad.
+pub fn transfer_funds<T>(
+    host: &mut impl HasHost<T>,
+    self_address: ContractAddress,
+    to: AccountAddress,
+    funds: Funds,
+) -> Result<(), Error> {
+    match funds {
+        Funds::Ccd(amount) => host.invoke_transfer(&to, amount)?,
+        Funds::Token(token, amount) => {
+            Cis2Client::new(token.contract).transfer(
+                host,
+                Transfer {
+                    token_id: token.token_id,
+                    amount,
+                    from: self_address.into(),
+                    to: to.into(),
+                    data: AdditionalData::empty(),
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculates the share of the total amount, pro-rata to the invested amount
+/// out of the raised funds.
+///
+/// The share is computed as a rational of 128-bit integers, so only the final
+/// result is rounded down, and it is never more than one unit less than the
+/// exact share, however small the investment is.
+pub fn pro_rata(total: TokenAmount, invested: Amount, raised: Amount) -> TokenAmount {
+    share_of(total, invested.micro_ccd, raised.micro_ccd)
+}
+
+/// Calculates the share of the total amount, pro-rata to the tokens bought
+/// out of the tokens sold, with the same precision as `pro_rata`.
+pub fn pro_rata_tokens(total: TokenAmount, bought: TokenAmount, sold: TokenAmount) -> TokenAmount {
+    share_of(total, bought.0, sold.0)
+}
+
+fn share_of(total: TokenAmount, part: u64, whole: u64) -> TokenAmount {
+    if whole == 0 {
+        return 0.into();
+    }
+
+    let share = (u128::from(total.0) * u128::from(part)) / u128::from(whole);
+    // Share never exceeds the total, since the part is never more than
+    // the whole
+    TokenAmount(share as u64)
+}
+
+/// Calculates the amount released until the given cycle, out of the amount
+/// linearly released over the given number of cycles.
+///
+/// Each cycle releases an equal part of the amount rounded down, while the
+/// final cycle also releases the remainder. Hence, the amount released over
+/// all the cycles is exactly the given amount.
+pub fn linear_release(amount: TokenAmount, cycles: Months, cycle: Months) -> TokenAmount {
+    if cycle >= cycles {
+        return amount;
+    }
+
+    TokenAmount((amount.0 / cycles) * cycle)
+}
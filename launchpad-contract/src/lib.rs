@@ -1,7 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use concordium_cis2::{
     AdditionalData, Cis2Client, OnReceivingCis2Params, TokenAmountU64 as TokenAmount, TokenIdU64,
-    TokenIdU8 as TokenID, TokenIdVec, Transfer, TransferParams,
+    TokenIdVec, Transfer,
 };
 use concordium_std::{
     bail, ensure, init, receive, Address, Amount, DeserialWithState, Entry, ExternContext,
@@ -10,16 +10,28 @@ use concordium_std::{
     InitContext, InitResult, Logger, ReceiveContext, Reject, Serial, StateBuilder, UnwrapAbort,
     Write, *,
 };
-use dex::{DexClient, GetExchangeParams, TokenInfo};
+use dex::{add_bounded_liquidity, AddLiquidityParams, DexClient, LiquidityVenue, TokenInfo};
 use errors::Error;
-use events::{ApproveEvent, CreateLaunchPadEvent, Event, RejectEvent, VestEvent};
-use helper::update_operator_of;
+use events::{
+    AdminProposeEvent, AdminUpdate, AdminUpdateEvent, ApproveEvent, CancelEvent, ClaimEvent,
+    CliffEvent, CreateLaunchPadEvent, DepositEvent, Event, InvestEvent, LiquidityEvent,
+    LockedClaimEvent, PauseEvent, PlatformPauseEvent, PlatformResumeEvent, ReclaimEvent,
+    RefundEvent, RejectEvent, ResumeEvent, RoleEvent, VestEvent, WithdrawEvent,
+};
+use helper::{linear_release, transfer_funds, update_operator_of, verify_product_token};
 use params::{
-    ApprovalParams, ClaimLockedParams, ClaimUnLockedParams, Claimer, CreateParams, InitParams,
-    LivePauseParams, VestParams,
+    AllowlistParams, ApprovalParams, ClaimAllParams, ClaimLockedParams, ClaimUnLockedParams,
+    Claimer, CreateParams, DepositData, HolderPositionParams, InitParams, LaunchPadKey, ListParams,
+    LivePauseParams, Months, RoleParams, UpgradeParams, VestParams, WithdrawParams,
+};
+use response::{
+    AllLaunchPads, HolderPosition, LaunchPadSummary, LaunchPadView, LaunchPadsPage, LaunchPadsView,
+    StateView,
+};
+use state::{
+    Funds, HolderInfo, LaunchPad, PaymentToken, ReleaseSchedule, Role, State, Status, TimePeriod,
+    STATE_VERSION,
 };
-use response::{AllLaunchPads, LaunchPadView, LaunchPadsView, StateView};
-use state::{HolderInfo, LaunchPad, Release, State, Status, TimePeriod};
 
 mod dex;
 mod errors;
@@ -37,6 +49,9 @@ pub type ContractResult<A> = Result<A, Error>;
 /// Alias for String as launch pad product name
 pub type ProductName = String;
 
+/// Alias for u32 as launch pad ID, assigned sequentially at creation
+pub type LaunchPadId = u32;
+
 /// Minimum Cliff duration allowed for a product before vesting
 /// in milliseconds.
 ///
@@ -57,14 +72,22 @@ const MAX_PAUSE_COUNT: u8 = 3;
 /// release duration for each cycle is 1 month.
 const CYCLE_DURATION: u64 = 2.678e9 as u64;
 
+/// Maximum percentage allowed for the admin allocation and
+/// liquidity shares
+const MAX_SHARE: u64 = 100;
+
+/// Maximum number of launch pads allowed to be listed in a
+/// single page
+const MAX_PAGE_LIMIT: u32 = 50;
+
 /// Alias for OnReceiveCIS2 ook params
-type OnReceiveCIS2Params = OnReceivingCis2Params<TokenID, TokenAmount>;
+type OnReceiveCIS2Params = OnReceivingCis2Params<TokenIdVec, TokenAmount>;
 
 /// Entry point which initializes the contract with new default state.
 ///
 /// The state is empty except that the user must provide admin parameters
 /// to be set while initialization.
-#[init(contract = "LaunchPad", parameter = "InitParams")]
+#[init(contract = "LaunchPad", parameter = "InitParams", event = "Event")]
 fn init(ctx: &InitContext, state_builder: &mut StateBuilder) -> InitResult<State> {
     // Getting the init params, which actually wraps
     // around admin information inside
@@ -73,10 +96,16 @@ fn init(ctx: &InitContext, state_builder: &mut StateBuilder) -> InitResult<State
     // Creating the default state with provided admin
     // information
     Ok(State {
+        version: STATE_VERSION,
         launchpads: state_builder.new_map(),
+        launchpad_ids: state_builder.new_map(),
         investors: state_builder.new_map(),
         admin: param.admin,
+        pending_admin: None,
+        roles: state_builder.new_map(),
         counter: 0,
+        pause_reason: None,
+        dexes: Vec::new(),
     })
 }
 
@@ -95,6 +124,9 @@ fn create_launchpad(
     amount: Amount,
     logger: &mut Logger,
 ) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Ensure that the sender is an account.
     ensure!(ctx.sender().is_account(), Error::OnlyAccount);
 
@@ -117,6 +149,18 @@ fn create_launchpad(
     // Ensure that the launch-pad active time period is valid
     params.timeperiod.ensure_is_period_valid(time_now)?;
 
+    // Ensure that the allowlist phase ends within the launch-pad
+    // active time period
+    ensure!(params.is_allowlist_valid(), Error::InCorrect);
+
+    // Ensure that the sale rounds, if provided, are ordered within the
+    // launch-pad active time period and do not exceed the allocated tokens
+    ensure!(params.is_rounds_valid(), Error::InCorrect);
+
+    // Ensure that the launch pads paid in a CIS2 token do not allocate
+    // funds for liquidity
+    params.ensure_payment_valid()?;
+
     // Ensure that the provided cliff time period is valid.
     // Cliff is consdiered only if it starts after the vesting
     // and has minimum duration of 7 days
@@ -125,32 +169,53 @@ fn create_launchpad(
         Error::InCorrect
     );
 
-    // Creating the Launch-pad from user defined params and
-    // getting the launch-pad ID
-    let (name, launch_pad) = LaunchPad::from_create_params(params, &mut host.state_builder);
+    // Ensure that the DEX picked to lock the liquidity, if any, is
+    // registered by the admin, otherwise the platform DEX is used
+    let dex = params.dex.unwrap_or(host.state().dex_address());
+    ensure!(host.state().is_dex_registered(&dex), Error::UnknownDex);
+
+    // Ensure that the product token is issued by a CIS2 compliant
+    // contract and the owner holds the allocated tokens, recording the
+    // token metadata URL reported by the contract
+    let token_metadata = verify_product_token(host, &params.product)?;
+
+    // Creating the Launch-pad from user defined params with
+    // the next sequential launch-pad ID
+    let id = host.state().counter;
+    let (name, launch_pad) = LaunchPad::from_create_params(
+        params,
+        id,
+        amount,
+        dex,
+        token_metadata,
+        &mut host.state_builder,
+    );
 
-    // Updating the contract State with new launchpad entry
-    match host.state_mut().launchpads.entry(name) {
+    // Indexing the launch-pad ID by its product name
+    match host.state_mut().launchpad_ids.entry(name) {
         // If the launch-pad with the same product name exists
         // it will not allow the launch-pad to be inserted
         Entry::Occupied(_) => {
             bail!(Error::Taken)
         }
-        // Or else it will insert the launch-pad in State and
-        // dispatch the launch pad creation event
+        // Or else it will index the launch-pad and dispatch the
+        // launch pad creation event
         Entry::Vacant(entry) => {
             logger.log(&Event::CREATED(CreateLaunchPadEvent {
+                launchpad_id: id,
                 launchpad_name: launch_pad.product_name(),
                 owner: launch_pad.get_product_owner(),
                 allocated_tokens: launch_pad.get_product_token_amount(),
                 base_price: launch_pad.product_base_price(),
             }))?;
 
-            entry.insert(launch_pad);
+            entry.insert(id);
         }
     };
 
-    // Incrementing the counter to track total launchpads
+    // Updating the contract State with new launchpad entry, and
+    // incrementing the counter to track total launchpads
+    let _ = host.state_mut().launchpads.insert(id, launch_pad);
     host.state_mut().counter += 1;
 
     Ok(())
@@ -169,28 +234,36 @@ fn approve_launchpad(
     host: &mut Host<State>,
     logger: &mut Logger,
 ) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Ensure that the sender is an account.
     ensure!(ctx.sender().is_account(), Error::OnlyAccount);
 
-    // Only admin is allowed to approve launch-pad for presale
-    ensure!(
-        ctx.sender() == host.state().admin_address().into(),
-        Error::UnAuthorized
-    );
+    // Only analyst is allowed to approve launch-pad for presale
+    host.state().ensure_role(ctx.sender(), Role::Analyst)?;
 
-    // Product name is passed as parameter to identify the
+    // ID or product name is passed as parameter to identify the
     // corresponding Launch-pad
     let params: ApprovalParams = ctx.parameter_cursor().get()?;
 
     // Getting the launch-pad to be approved and updating its
     // status to LIVE
-    let mut launch_pad = host.state_mut().get_mut_launchpad(params.product_name)?;
+    let mut launch_pad = host.state_mut().get_mut_launchpad(params.launch_pad)?;
+
+    // Only the launch-pads in review are approved or rejected, so
+    // that the registeration fee is settled once, and a launch-pad
+    // which is already live or canceled is never overturned
+    ensure!(launch_pad.status == Status::INREVIEW, Error::JobFailed);
+
+    let fee_paid = launch_pad.fee_paid;
 
     let transfer_to = if params.approve {
         // Updating the launch-pad status to approved
         launch_pad.status = Status::APPROVED;
 
         logger.log(&Event::APPROVED(ApproveEvent {
+            launchpad_id: launch_pad.id,
             launchpad_name: launch_pad.product_name(),
         }))?;
 
@@ -203,6 +276,7 @@ fn approve_launchpad(
         launch_pad.status = Status::REJECTED;
 
         logger.log(&Event::REJECTED(RejectEvent {
+            launchpad_id: launch_pad.id,
             launchpad_name: launch_pad.product_name(),
         }))?;
 
@@ -214,7 +288,7 @@ fn approve_launchpad(
 
     // Refunding the product owner in case if the launch-pad
     // is rejected
-    host.invoke_transfer(&transfer_to, host.state().admin_registeration_fee())?;
+    host.invoke_transfer(&transfer_to, fee_paid)?;
 
     Ok(())
 }
@@ -223,7 +297,7 @@ fn approve_launchpad(
     contract = "LaunchPad",
     name = "Deposit",
     mutable,
-    parameter = "OnReceivingCis2Params<TokenID, TokenAmount>",
+    parameter = "OnReceivingCis2Params<TokenIdVec, TokenAmount>",
     error = "Error",
     enable_logger
 )]
@@ -232,6 +306,9 @@ fn deposit_tokens(
     host: &mut Host<State>,
     logger: &mut Logger,
 ) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // This entry point is only meant to be invoked by CIS2 contract
     // given by the product owner in launch-pad params
     let contract = match ctx.sender() {
@@ -240,8 +317,8 @@ fn deposit_tokens(
     };
 
     // Parsing the parameters caught by OnReceive hook,
-    // We expect to receive additional data as the product
-    // name string type in the params
+    // We expect to receive additional data as the typed
+    // deposit payload in the params
     let OnReceiveCIS2Params {
         token_id,
         amount,
@@ -249,11 +326,11 @@ fn deposit_tokens(
         data,
     } = ctx.parameter_cursor().get()?;
 
-    let product_name = String::from_utf8(data.as_ref().to_owned()).unwrap();
+    let payload: DepositData = from_bytes(data.as_ref())?;
 
     // Fetching the launch-pad from the state if the correct
-    // product name is supplied
-    let mut launch_pad = host.state_mut().get_mut_launchpad(product_name)?;
+    // ID or product name is supplied
+    let mut launch_pad = host.state_mut().get_mut_launchpad(payload.launch_pad())?;
 
     // Making sure that the deposit is made by the product
     // owner
@@ -269,25 +346,45 @@ fn deposit_tokens(
         Error::UnAuthorized
     );
 
-    // Ensure other details, such as the correct token amount
-    // is received or we have received the correct tokens by
-    // matching the token ID given in launch-pad params
+    // Tokens are only deposited once the launch pad is approved
+    // for presale, and until it is made LIVE
+    ensure!(launch_pad.status == Status::APPROVED, Error::NotApproved);
+
+    // Ensure that we have received the correct tokens by matching
+    // the token ID given in launch-pad params
     ensure!(
-        amount == launch_pad.get_product_token_amount(),
+        token_id == launch_pad.get_product_token_id(),
         Error::InCorrect
     );
+
+    // Deposits are accumulated until the allocated tokens are fully
+    // funded, and can never exceed them
+    let deposited = launch_pad.token_balance + amount;
     ensure!(
-        token_id == launch_pad.get_product_token_id(),
-        Error::InCorrect
+        deposited <= launch_pad.get_product_token_amount(),
+        Error::Limit
     );
+    launch_pad.token_balance = deposited;
 
-    // If every claim is valid, Launch-pad is made LIVE for presale
-    // for the current product
+    logger.log(&Event::DEPOSITED(DepositEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        amount,
+        deposited,
+    }))?;
+
+    if deposited < launch_pad.get_product_token_amount() {
+        return Ok(());
+    }
+
+    // If the allocated tokens are fully funded, Launch-pad is made
+    // LIVE for presale for the current product
     launch_pad.status = Status::LIVE;
 
     // Dispatching the event as notification when the vesting start
     // as soon as the allocated tokens are deposited
     logger.log(&Event::VESTINGSTARTED(VestEvent {
+        launchpad_id: launch_pad.id,
         launchpad_name: launch_pad.product_name(),
         vesting_time: launch_pad.timeperiod,
         vesting_limits: launch_pad.vest_limits.clone(),
@@ -301,17 +398,25 @@ fn deposit_tokens(
     name = "LivePause",
     mutable,
     parameter = "LivePauseParams",
-    error = "Error"
+    error = "Error",
+    enable_logger
 )]
-fn live_pause(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+fn live_pause(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Only Account is supposed to invoke this method
     ensure!(ctx.sender().is_account(), Error::OnlyAccount);
 
     // Reading parameters
     let params: LivePauseParams = ctx.parameter_cursor().get()?;
 
-    // Getting the launch pad from state identified by the product name
-    let mut launch_pad = host.state_mut().get_mut_launchpad(params.poduct_name)?;
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(params.launch_pad)?;
 
     // Product owner (developer) is only allowed to pause
     // the launch pad
@@ -351,6 +456,12 @@ fn live_pause(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()
         launch_pad.pause.timeperiod = params.pause_duration;
         launch_pad.pause.count += 1;
 
+        logger.log(&Event::PAUSED(PauseEvent {
+            launchpad_id: launch_pad.id,
+            launchpad_name: launch_pad.product_name(),
+            pause_duration: params.pause_duration,
+        }))?;
+
         return Ok(());
     }
 
@@ -368,6 +479,57 @@ fn live_pause(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()
     // Resetting the pause durations
     launch_pad.pause.timeperiod = TimePeriod::default();
 
+    logger.log(&Event::RESUMED(ResumeEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "UpdateAllowlist",
+    mutable,
+    parameter = "AllowlistParams",
+    error = "Error"
+)]
+fn update_allowlist(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
+    // Only Account is supposed to invoke this method
+    ensure!(ctx.sender().is_account(), Error::OnlyAccount);
+
+    // Reading parameters
+    let params: AllowlistParams = ctx.parameter_cursor().get()?;
+
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(params.launch_pad)?;
+
+    // Product owner (developer) is only allowed to manage the
+    // allowlist of the launch pad
+    ensure!(
+        ctx.sender()
+            .matches_account(&launch_pad.get_product_owner()),
+        Error::UnAuthorized
+    );
+
+    // Allowlist can only be updated if the launch pad has an allowlist
+    // phase which is not yet over
+    ensure!(
+        launch_pad.is_allowlist_phase(ctx.metadata().block_time()),
+        Error::JobFailed
+    );
+
+    for (account, allocation) in params.allocations {
+        if allocation == 0.into() {
+            launch_pad.allowlist.remove(&account);
+        } else {
+            let _ = launch_pad.allowlist.insert(account, allocation);
+        }
+    }
+
     Ok(())
 }
 
@@ -377,9 +539,18 @@ fn live_pause(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()
     mutable,
     parameter = "VestParams",
     error = "Error",
+    enable_logger,
     payable
 )]
-fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> ContractResult<()> {
+fn vest(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    amount: Amount,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Only Account is supposed to invoke this method
     let holder = match ctx.sender() {
         Address::Account(acc) => acc,
@@ -389,29 +560,169 @@ fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> Contrac
     // Reading parameters
     let params: VestParams = ctx.parameter_cursor().get()?;
 
-    // Getting the contract's core state and its builder
-    let (state, state_builder) = host.state_and_builder();
+    // Launch pads accepting a CIS2 payment token are only invested
+    // through the `VestCis2` hook
+    ensure!(
+        host.state()
+            .get_launchpad(params.launch_pad.clone())?
+            .payment_token
+            .is_none(),
+        Error::InCorrect
+    );
 
-    // Getting the launch pad from state identified by the product name
-    let mut launch_pad = state.get_mut_launchpad(params.product_name)?;
+    let excess = invest(ctx, host, logger, holder, params, amount)?;
 
-    // Make sure that the launch pad is not paused, is not canceled
-    // or is not finished, either due to vesting duration elapsed or
-    // due to hard cap limit reached
+    // Refunding the excess amount paid by the holder
+    if excess > Amount::zero() {
+        host.invoke_transfer(&holder, excess)?;
+    }
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "VestCis2",
+    mutable,
+    parameter = "OnReceivingCis2Params<TokenIdVec, TokenAmount>",
+    error = "Error",
+    enable_logger
+)]
+fn vest_cis2(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
+    // This entry point is only meant to be invoked by the CIS2 contract
+    // of the payment token accepted by the launch pad
+    let contract = match ctx.sender() {
+        Address::Account(_) => bail!(Error::OnlyContract),
+        Address::Contract(cis2_contract) => cis2_contract,
+    };
+
+    // Parsing the parameters caught by OnReceive hook, We expect to
+    // receive the vesting params as additional data
+    let OnReceivingCis2Params {
+        token_id,
+        amount,
+        from,
+        data,
+    }: OnReceivingCis2Params<TokenIdVec, TokenAmount> = ctx.parameter_cursor().get()?;
+
+    // Only Account is supposed to invest through the payment token
+    let holder = match from {
+        Address::Account(acc) => acc,
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+    };
+
+    let params: VestParams = from_bytes(data.as_ref())?;
+
+    // Ensure that the received tokens are the payment token accepted
+    // by the launch pad
+    let launch_pad = host.state().get_launchpad(params.launch_pad.clone())?;
     ensure!(
-        !launch_pad.is_paused() && !launch_pad.is_canceled() && !launch_pad.is_finished(ctx),
-        Error::JobFailed
+        launch_pad.accepts_payment_token(contract, &token_id),
+        Error::UnAuthorized
     );
+    let payment_token = launch_pad.payment_token.clone();
+    drop(launch_pad);
+
+    // Payment token amount is denominated in the smallest unit of the
+    // token, and so are the amounts of the launch pad
+    let excess = invest(
+        ctx,
+        host,
+        logger,
+        holder,
+        params,
+        PaymentToken::to_amount(amount),
+    )?;
+
+    // Refunding the excess amount paid by the holder in payment token
+    if excess > Amount::zero() {
+        transfer_funds(
+            host,
+            ctx.self_address(),
+            holder,
+            Funds::new(payment_token, excess),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Invests the given amount on behalf of the holder in the launch pad
+/// identified in the vesting params, paid either in CCD or in the payment
+/// token of the launch pad.
+///
+/// Returns the excess amount paid by the holder, which is to be refunded
+/// by the caller in the same currency.
+fn invest(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+    holder: AccountAddress,
+    params: VestParams,
+    amount: Amount,
+) -> ContractResult<Amount> {
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(params.launch_pad)?;
+
+    // Make sure that the launch pad is LIVE, i.e. the allocated tokens are
+    // fully deposited, and it is not paused or canceled
+    ensure!(launch_pad.is_live(), Error::JobFailed);
+
+    // Make sure that the launch pad is not finished, either due to vesting
+    // duration elapsed or due to hard cap limit reached
+    ensure!(!launch_pad.is_finished(ctx), Error::JobFailed);
+
+    // Vesting is only allowed while one of the sale rounds is active
+    let round_index = match launch_pad.active_round(ctx.metadata().block_time()) {
+        Some(index) => index,
+        None => bail!(Error::JobFailed),
+    };
+    let round = launch_pad.rounds[round_index as usize].clone();
 
     // Verify whether the payable vesting amount received is within the
-    // min and max vesting allowed
+    // min and max vesting allowed in the active round
+    ensure!(
+        params.token_amount >= round.vest_limits.min
+            && params.token_amount <= round.vest_limits.max,
+        Error::Insufficient
+    );
+
+    // Ensure that enough tokens are left for presale
     ensure!(
-        params.token_amount >= launch_pad.vest_min()
-            && params.token_amount <= launch_pad.vest_max(),
+        params.token_amount <= launch_pad.available_tokens,
         Error::Insufficient
     );
 
-    let vest_max = launch_pad.vest_max();
+    // Ensure that the round allocation is not exceeded
+    ensure!(
+        params.token_amount <= round.available_tokens(),
+        Error::Limit
+    );
+
+    // Calculating the amount in CCD required to buy the tokens at the
+    // round token price, investment paying less than that is rejected
+    let required = round.price_of(params.token_amount)?;
+    ensure!(amount >= required, Error::Insufficient);
+
+    // Any amount paid more than required is refunded to the holder
+    let excess = amount - required;
+
+    // Investment is rejected if it exceeds the hard cap, once the hard
+    // cap is reached the launch pad is finished
+    launch_pad.ensure_within_hard_cap(required)?;
+
+    // During the allowlist phase, only the allowlisted holders are
+    // allowed to vest within their allocations
+    if launch_pad.is_allowlist_phase(ctx.metadata().block_time()) {
+        launch_pad.use_allocation(holder, params.token_amount)?;
+    }
 
     // Updating or inserting the holder(investor) depending whether the
     // holder is new or existing in the launch pad state
@@ -420,39 +731,63 @@ fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> Contrac
         // the holders list along with his invested amount
         // and claimable tokens
         Entry::Vacant(entry) => {
-            entry.insert(HolderInfo {
+            let mut holder_info = HolderInfo {
                 tokens: params.token_amount,
-                invested: amount,
-                release_data: Release {
-                    unlocked: state_builder.new_map(),
-                    locked: state_builder.new_map(),
-                },
-            });
+                invested: required,
+                claimed_tokens: 0.into(),
+                claimed_lp_tokens: 0.into(),
+                refunded: false,
+                round_tokens: Vec::new(),
+                tokens_settled: false,
+                lp_tokens_settled: false,
+            };
+            holder_info.add_round_tokens(round_index, params.token_amount);
+            entry.insert(holder_info);
         }
         // If holder already exist in the launch pad, then
         // just update it's previous amount and claimable
         // tokens.
         Entry::Occupied(mut entry) => {
-            let _ = entry.modify(|holder_info| {
+            entry.modify(|holder_info| {
                 // Ensure that holder does not exceeds the max vesting
-                // limit allowed
+                // limit allowed in the active round
                 ensure!(
-                    holder_info.tokens + params.token_amount < vest_max,
+                    holder_info.tokens_in_round(round_index) + params.token_amount
+                        <= round.vest_limits.max,
                     Error::Limit
                 );
-                holder_info.invested += amount;
+                holder_info.invested += required;
                 holder_info.tokens += params.token_amount;
+                holder_info.add_round_tokens(round_index, params.token_amount);
                 Ok(())
-            });
+            })?;
         }
     }
 
     // Updating the collected investment and allocated tokens sold so far
     // by the product
-    launch_pad.collected += amount;
+    launch_pad.collected += required;
     launch_pad.sold_tokens += params.token_amount;
     launch_pad.available_tokens -= params.token_amount;
 
+    // Updating the progress of the active round
+    let round = &mut launch_pad.rounds[round_index as usize];
+    round.collected += required;
+    round.sold_tokens += params.token_amount;
+
+    logger.log(&Event::INVESTED(InvestEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        holder,
+        round: round_index,
+        token_amount: params.token_amount,
+        amount: required,
+    }))?;
+
+    // Sale is finished as soon as the hard cap is reached, and the
+    // launch pad enters the cliff period
+    log_cliff_started(ctx, &mut launch_pad, logger)?;
+
     // Get the amount of tokens allocated for presale by the owner
     let allocated_tokens = launch_pad.product.allocated_tokens;
     // Check if the product has acheived soft cap
@@ -460,7 +795,7 @@ fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> Contrac
     // Check if the product has paid the soft cap share to the platform
     let allocation_paid = launch_pad.allocation_paid;
 
-    let product_name = launch_pad.product_name();
+    let id = launch_pad.id;
 
     drop(launch_pad);
 
@@ -474,13 +809,14 @@ fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> Contrac
         let allocated_cut =
             ((allocated_tokens.0 * host.state().admin_allocation_share()) / 100).into();
         let admin_address = host.state().admin_address();
-        let mut launchpad = host.state_mut().get_mut_launchpad(product_name.clone())?;
+        let mut launchpad = host.state_mut().get_mut_launchpad(id.into())?;
         let token_id = launchpad.get_product_token_id();
 
         let cis2_client = Cis2Client::new(launchpad.get_cis2_contract());
 
         launchpad.allocation_paid = true;
         launchpad.available_tokens -= allocated_cut;
+        launchpad.token_balance -= allocated_cut;
 
         drop(launchpad);
 
@@ -506,18 +842,38 @@ fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> Contrac
     match host.state_mut().investors.entry(holder) {
         // Insert the new holder to the state with launch pad ID
         Entry::Vacant(entry) => {
-            entry.insert(vec![product_name]);
+            entry.insert(vec![id]);
         }
         // Update the existing holder in the state with launch pad ID
         Entry::Occupied(mut entry) => {
             entry.modify(|launchpads| {
-                if !launchpads.contains(&product_name) {
-                    launchpads.push(product_name);
+                if !launchpads.contains(&id) {
+                    launchpads.push(id);
                 }
             });
         }
     }
 
+    Ok(excess)
+}
+
+/// Logs the start of the cliff period of the launch pad once, from the
+/// first state-changing call after its sale is finished, either by reaching
+/// the hard cap or by the vesting duration being elapsed.
+fn log_cliff_started<S: HasStateApi>(
+    ctx: &impl HasCommonData,
+    launch_pad: &mut LaunchPad<S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<()> {
+    if let Some(from) = launch_pad.start_cliff(ctx) {
+        logger.log(&Event::CLIFFSTARTED(CliffEvent {
+            launchpad_id: launch_pad.id,
+            launchpad_name: launch_pad.product_name(),
+            from,
+            to: launch_pad.lock_up.cliff,
+        }))?;
+    }
+
     Ok(())
 }
 
@@ -526,20 +882,28 @@ fn vest(ctx: &ReceiveContext, host: &mut Host<State>, amount: Amount) -> Contrac
     name = "ClaimTokens",
     mutable,
     parameter = "ClaimUnLockedParams",
-    error = "Error"
+    error = "Error",
+    enable_logger
 )]
-fn claim_tokens(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+fn claim_tokens(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Only Account is supposed to invoke this method
     let holder = match ctx.sender() {
         Address::Account(acc) => acc,
         Address::Contract(_) => bail!(Error::OnlyAccount),
     };
 
-    // Reading the product name to identify the launch pad
+    // Reading the ID or product name to identify the launch pad
     let params: ClaimUnLockedParams = ctx.parameter_cursor().get()?;
 
-    // Getting the launch pad from state identified by the product name
-    let launch_pad = host.state().get_launchpad(params.product_name.clone())?;
+    // Getting the launch pad from state identified by the ID or product name
+    let launch_pad = host.state().get_launchpad(params.launch_pad.clone())?;
 
     // Make sure that the launch pad is not paused, is not canceled
     // or is finished. As well as the cliff duration has elapsed
@@ -547,71 +911,111 @@ fn claim_tokens(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<
         !launch_pad.is_canceled() && launch_pad.is_finished(ctx),
         Error::JobFailed
     );
-    // ensure!(!launch_pad.is_canceled(), Error::Canceled);
-    // ensure!(launch_pad.is_finished(ctx), Error::Vesting);
-
-    if let Some(cycle_details) = launch_pad
-        .get_holder_info(holder)?
-        .release_data
-        .unlocked
-        .get(&params.cycle)
-    {
-        let (token_amount, timestamp, claimed) = *cycle_details;
-
-        // Ensuring that this cycle is not already claimed and cycle
-        // duration of 1 month is passed since the last cycle.
-        ensure!(!claimed, Error::Claimed);
-        ensure!(ctx.metadata().block_time() >= timestamp, Error::NotElapsed);
+    launch_pad.ensure_cliff_elapsed(ctx.metadata().block_time())?;
 
-        let cis2_contract = launch_pad.get_cis2_contract();
-        let token_id = launch_pad.get_product_token_id();
+    // Return early with error if the cycle number supplied in
+    // claim params does not exist.
+    let cycle = params.cycle as Months;
+    let schedule = launch_pad
+        .release_schedule
+        .as_ref()
+        .ok_or(Error::InCorrect)?;
+    ensure!(cycle > 0 && cycle <= schedule.cycles, Error::InCorrect);
+
+    // Ensuring that the cycle duration of 1 month is passed since the
+    // last cycle, and the tokens released until this cycle are not
+    // already claimed.
+    ensure!(
+        ctx.metadata().block_time() >= schedule.cycle_time(cycle),
+        Error::NotElapsed
+    );
 
-        // Updating the information regarding the current release cycle
-        // and changing its claimed status to true
-        host.state_mut()
-            .get_mut_launchpad(params.product_name)?
-            .set_holder_unlocked_release_info(holder, params.cycle, true);
+    let cis2_contract = launch_pad.get_cis2_contract();
+    let token_id = launch_pad.get_product_token_id();
 
-        // Here are the allocated tokens transfered to the holder based on
-        // the current release cycle count.
-        Cis2Client::new(cis2_contract).transfer(
-            host,
-            Transfer {
-                token_id,
-                amount: token_amount,
-                from: ctx.self_address().into(),
-                to: holder.into(),
-                data: AdditionalData::empty(),
-            },
-        )?;
+    drop(launch_pad);
 
-        return Ok(());
-    }
+    // Updating the amount of tokens claimed by the holder
+    let mut launch_pad = host.state_mut().get_mut_launchpad(params.launch_pad)?;
+    let token_amount = launch_pad
+        .claim_tokens(holder, cycle)?
+        .ok_or(Error::Claimed)?;
+    launch_pad.token_balance -= token_amount;
 
-    // Return early with error if the cycle number supplied in
-    // claim params does not exist.
-    Err(Error::InCorrect)
+    logger.log(&Event::CLAIMED(ClaimEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        holder,
+        cycle: params.cycle,
+        amount: token_amount,
+    }))?;
+
+    drop(launch_pad);
+
+    // Here are the allocated tokens transfered to the holder based on
+    // the current release cycle count.
+    Cis2Client::new(cis2_contract).transfer(
+        host,
+        Transfer {
+            token_id,
+            amount: token_amount,
+            from: ctx.self_address().into(),
+            to: holder.into(),
+            data: AdditionalData::empty(),
+        },
+    )?;
+
+    Ok(())
 }
 
 #[receive(
     contract = "LaunchPad",
     name = "WithdrawFunds",
     mutable,
-    parameter = "String",
-    error = "Error"
+    parameter = "WithdrawParams",
+    error = "Error",
+    enable_logger
 )]
-fn withdraw_raised(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+fn withdraw_raised(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Reading the ID or product name to identify the launch pad, along
+    // with the bounds of the liquidity to be added
+    let params: WithdrawParams = ctx.parameter_cursor().get()?;
+
+    // Liquidity is added to the DEX picked for the launch pad
+    let dex_contract = host.state().get_launchpad(params.launch_pad.clone())?.dex;
+
+    withdraw_raised_with(ctx, host, logger, params, &mut DexClient::new(dex_contract))
+}
+
+/// Withdraws the raised funds of the launch pad to the product owner, after
+/// locking the liquidity allocation in the given venue.
+fn withdraw_raised_with<S, H, V>(
+    ctx: &impl HasReceiveContext,
+    host: &mut H,
+    logger: &mut impl HasLogger,
+    params: WithdrawParams,
+    venue: &mut V,
+) -> ContractResult<()>
+where
+    S: HasStateApi,
+    H: HasHost<State<S>, StateApiType = S>,
+    V: LiquidityVenue<H>,
+{
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Only Account is supposed to invoke this method
     let owner = match ctx.sender() {
         Address::Account(acc) => acc,
         Address::Contract(_) => bail!(Error::OnlyAccount),
     };
 
-    // Reading the product name to identify the launch pad
-    let product_name: ProductName = ctx.parameter_cursor().get()?;
-
-    // Getting the launch pad from state identified by the product name
-    let launch_pad = host.state().get_launchpad(product_name.clone())?;
+    // Getting the launch pad from state identified by the ID or product name
+    let launch_pad = host.state().get_launchpad(params.launch_pad.clone())?;
 
     // Make sure that the transaction is authorized
     ensure!(owner == launch_pad.get_product_owner(), Error::UnAuthorized);
@@ -629,173 +1033,205 @@ fn withdraw_raised(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResu
     if !launch_pad.withdrawn && launch_pad.reached_soft_cap() {
         ensure!(launch_pad.reached_soft_cap(), Error::SoftCap);
 
-        // Calculating the amount of funds in CCD to be locked
-        // in liquidity according to the percentage provided by
-        // the owner
-        let ccd_lp_alloc = Amount::from_micro_ccd(
-            (launch_pad.collected.micro_ccd * launch_pad.liquidity_details.liquidity_allocation)
-                / 100,
-        );
-
-        // Tokens from the ICO of product will also be locked in liquidity
-        // and the amount of tokens will be designated reflected by the base
-        // price of the token in ccd and the amount of CCD being locked.
-        let tokens_for_lp = ccd_lp_alloc.micro_ccd / launch_pad.product_base_price().micro_ccd;
-
-        // Remaining amount in CCD that can be withdrawn after the liquidity
-        // allocation
-        let withdrawable = launch_pad.collected - ccd_lp_alloc;
-
+        let id = launch_pad.id;
+        let product_name = launch_pad.product_name();
         let token_id = launch_pad.get_product_token_id();
         let cis2_contract = launch_pad.get_cis2_contract();
-        let dex_contract = host.state().dex_address();
         let raised_funds_ccd = launch_pad.collected;
         let liquidity_details = launch_pad.liquidity_details.clone();
         let product_sold_tokens = launch_pad.sold_tokens;
         let lock_up_release_cycles = launch_pad.lock_up.release_cycles;
+        let payment_token = launch_pad.payment_token.clone();
+        let liquidity_price = launch_pad.liquidity_price();
+        let cliff = launch_pad.lock_up.cliff;
 
-        // Making DEX as an operator of Launch pad in CIS2 contract
-        update_operator_of(host, cis2_contract, dex_contract.into())?;
-
-        // Ensure that DEX has been added as the oprators
-        let response = Cis2Client::new(cis2_contract).operator_of(
-            host,
-            ctx.self_address().into(),
-            host.state().dex_address().into(),
-        )?;
-        ensure!(response, Error::JobFailed);
+        drop(launch_pad);
 
-        // Adding the liquidity to the Platform's DEX and invoking
-        DexClient::new(dex_contract).add_liquidity(
-            host,
-            token_id,
-            tokens_for_lp.into(),
-            ccd_lp_alloc,
-            cis2_contract,
-        )?;
+        // Funds raised in a CIS2 payment token are not locked in liquidity,
+        // since the platform's DEX only supports pairs with CCD. Hence, the
+        // complete raised funds are withdrawable.
+        let (withdrawable, tokens_for_lp, liquidity) = if payment_token.is_some() {
+            (raised_funds_ccd, 0, None)
+        } else {
+            // Calculating the amount of funds in CCD to be locked
+            // in liquidity according to the percentage provided by
+            // the owner
+            let ccd_lp_alloc = Amount::from_micro_ccd(
+                (raised_funds_ccd.micro_ccd * liquidity_details.liquidity_allocation) / 100,
+            );
 
-        let exchange = DexClient::new(dex_contract).get_exchange(
-            host,
-            &GetExchangeParams {
-                holder: Address::Contract(ctx.self_address()),
-                token: TokenInfo {
-                    id: TokenIdVec(token_id.0.to_ne_bytes().into()),
-                    address: cis2_contract,
+            // Tokens from the ICO of product will also be locked in liquidity
+            // and the amount of tokens will be designated reflected by the
+            // liquidity price of the token in ccd, which is the price of the
+            // last sale round, and the amount of CCD being locked. These
+            // tokens are taken from the tokens sold, hence can not exceed them.
+            let tokens_for_lp = ccd_lp_alloc.micro_ccd / liquidity_price.micro_ccd;
+            ensure!(tokens_for_lp <= product_sold_tokens.0, Error::Insufficient);
+
+            // Remaining amount in CCD that can be withdrawn after the liquidity
+            // allocation
+            let withdrawable = raised_funds_ccd - ccd_lp_alloc;
+
+            // Making DEX as an operator of Launch pad in CIS2 contract
+            update_operator_of(host, cis2_contract, venue.address().into())?;
+
+            // Ensure that DEX has been added as the oprators
+            let response = Cis2Client::new(cis2_contract).operator_of(
+                host,
+                ctx.self_address().into(),
+                venue.address().into(),
+            )?;
+            ensure!(response, Error::JobFailed);
+
+            // Adding the liquidity to the launch pad's DEX within the bounds
+            // provided by the owner, and getting the LPTokens received
+            let (lp_token_id, lp_received) = add_bounded_liquidity(
+                venue,
+                host,
+                Address::Contract(ctx.self_address()),
+                &AddLiquidityParams {
+                    token: TokenInfo {
+                        id: token_id,
+                        address: cis2_contract,
+                    },
+                    token_amount: tokens_for_lp.into(),
                 },
-            },
-        )?;
+                ccd_lp_alloc,
+                liquidity_price,
+                &params,
+            )?;
+
+            // Platform will charge a certain amount from allocated liquidity
+            // in exchange of DEX services it provides to the product.
+            // Amount that is charged will be according to the launch pad policies
+            // and it will be charge from the received LPTokens.
+            let platform_lp_share = (lp_received * host.state().admin_liquidity_share()).0 / 100;
+
+            // Calculating the remaining LPTokens after platform's cut from the
+            // received LPTokens.
+            // Allocated LPTokens are divided in half because, equally half of the
+            // LPTokens dividend belongs to the product owner and the other half
+            // is distributed among the holders in accordance with their percentage
+            // contribution in the product's launch pad. Odd LPToken left over by
+            // the division belongs to the product owner.
+            // This is all aligned with the platform's policies to prevent rug-pull
+            // as much as possible.
+            let lp_remaining = lp_received.0 - platform_lp_share;
+            let lp_allocated: TokenAmount = (lp_remaining / 2).into();
+            let owner_lp_allocated: TokenAmount = (lp_remaining - lp_remaining / 2).into();
+
+            // Transfering the DEX service charges to the platform as the LPTokens.
+            let admin = host.state().admin_address();
+            venue.transfer_lp_tokens(
+                host,
+                vec![Transfer {
+                    token_id: lp_token_id,
+                    amount: platform_lp_share.into(),
+                    from: ctx.self_address().into(),
+                    to: concordium_cis2::Receiver::Account(admin),
+                    data: AdditionalData::empty(),
+                }],
+            )?;
+
+            logger.log(&Event::LIQUIDITYADDED(LiquidityEvent {
+                launchpad_id: id,
+                launchpad_name: product_name.clone(),
+                amount: ccd_lp_alloc,
+                token_amount: tokens_for_lp.into(),
+                lp_token_id,
+                lp_tokens: lp_received,
+            }))?;
 
-        // Platform will charge a certain amount from allocated liquidity
-        // in exchange of DEX services it provides to the product.
-        // Amount that is charged will be according to the launch pad policies
-        // and it will be charge from the received LPTokens.
-        let platform_lp_share =
-            (exchange.lp_tokens_supply * host.state().admin_liquidity_share()).0 / 100;
-
-        // Calculating the remaining LPTokens after platform's cut from the
-        // received LPTokens.
-        // Allocated LPTokens are divided in half because, equally half of the
-        // LPTokens dividend belongs to the product owner and the other half
-        // is distributed among the holders in accordance with their percentage
-        // contribution in the product's launch pad.
-        // This is all aligned with the platform's policies to prevent rug-pull
-        // as much as possible.
-        let lp_allocated: TokenAmount =
-            ((exchange.lp_tokens_supply.0 - platform_lp_share) / 2).into();
+            (
+                withdrawable,
+                tokens_for_lp,
+                Some((lp_token_id, lp_allocated, owner_lp_allocated)),
+            )
+        };
 
-        // Transfering the DEX service charges to the platform as the LPTokens.
-        DexClient::new(host.state().dex_address()).transfer(
+        // Transfering the withdrawable amount to the owner in CCD or in
+        // the payment token of the launch pad
+        transfer_funds(
             host,
-            TransferParams::<TokenIdU64, TokenAmount>(vec![Transfer {
-                token_id: exchange.lp_token_id,
-                amount: platform_lp_share.into(),
-                from: ctx.self_address().into(),
-                to: concordium_cis2::Receiver::Account(host.state().admin_address()),
-                data: AdditionalData::empty(),
-            }]),
+            ctx.self_address(),
+            owner,
+            Funds::new(payment_token, withdrawable),
         )?;
+        // Set the withdrawn flag in launchpad state and deduct the tokens
+        // locked in liquidity from the launch pad token balance
+        let mut launch_pad = host.state_mut().get_mut_launchpad(id.into())?;
+        launch_pad.withdrawn = true;
+        launch_pad.token_balance = launch_pad
+            .token_balance
+            .0
+            .checked_sub(tokens_for_lp)
+            .ok_or(Error::Insufficient)?
+            .into();
+        drop(launch_pad);
 
-        // Transfering the withdrawable amount to the owner in CCD
-        host.invoke_transfer(&owner, withdrawable)?;
-        // Set the withdrawn flag in launchpad state
-        host.state_mut()
-            .get_mut_launchpad(product_name.clone())
-            .unwrap()
-            .withdrawn = true;
-
-        // Updating each holder's information regarding the locked release
-        // cycles. LPTokens will be linearly released over the number of
-        // months provided by the product owner.
-        // Amount of LPTokens, being released in each cycle for any holder,
-        // solely depends on its percentage contribution to the ICO.
-        for (_, mut holder_info) in host
-            .state_mut()
-            .get_mut_launchpad(product_name.clone())
-            .unwrap()
-            .get_holders_mut()
-        {
-            let holder_contribution =
-                (holder_info.invested.micro_ccd * 100) / raised_funds_ccd.micro_ccd;
-
-            let holder_lpts = (lp_allocated * holder_contribution).0 / 100;
-
-            let holder_ico_tokens =
-                ((product_sold_tokens - tokens_for_lp.into()) * holder_contribution).0 / 100;
-
-            for i in 0..lock_up_release_cycles {
-                let cycle_count = i + 1;
-                let tokens_release_amount = (holder_ico_tokens / lock_up_release_cycles).into();
-                let timestamp =
-                    ((ctx.metadata().block_time().millis + CYCLE_DURATION) * cycle_count).into();
+        logger.log(&Event::WITHDRAWN(WithdrawEvent {
+            launchpad_id: id,
+            launchpad_name: product_name.clone(),
+            owner,
+            amount: withdrawable,
+        }))?;
 
-                holder_info.insert_unlocked_cycle(
-                    cycle_count as u8,
-                    tokens_release_amount,
-                    timestamp,
-                );
-            }
+        let mut launch_pad = host.state_mut().get_mut_launchpad(id.into()).unwrap();
+
+        // Sale finished by reaching the hard cap has already entered the
+        // cliff period while vesting, otherwise it is entered once the
+        // vesting duration is over
+        log_cliff_started(ctx, &mut launch_pad, logger)?;
+
+        // Setting the release schedule shared by all the holders, rather
+        // than computing the release cycles of each holder, so that the
+        // withdrawal does not depend on the number of holders. Tokens are
+        // linearly released over the number of months provided by the
+        // product owner, starting once the cliff period is over. Amount
+        // released to any holder solely depends on its pro-rata share of
+        // the ICO, and is computed when claimed.
+        launch_pad.release_schedule = Some(ReleaseSchedule {
+            start: cliff,
+            tokens: product_sold_tokens
+                .0
+                .checked_sub(tokens_for_lp)
+                .ok_or(Error::Insufficient)?
+                .into(),
+            cycles: lock_up_release_cycles,
+            liquidity: liquidity.map(|(lp_token_id, lp_allocated, _)| (lp_token_id, lp_allocated)),
+            lp_cycles: liquidity_details.release_cycles,
+            distributed_tokens: 0.into(),
+            tokens_settled: 0.into(),
+            distributed_lp_tokens: 0.into(),
+            lp_tokens_settled: Amount::zero(),
+        });
 
-            for i in 0..liquidity_details.release_cycles {
-                let lpt_amount = (holder_lpts / liquidity_details.release_cycles).into();
+        // Pre-computing the release cycle information for the product owner
+        // locked funds release (LPTokens). For product owner, locked funds
+        // are released over a year after the cliff in 3 cycles which are
+        // equally separated by 4 months interval.
+        // This is all aligned with the platform's policies to prevent rug-pull
+        // as much as possible.
+        if let Some((lp_token_id, _, owner_lp_allocated)) = liquidity {
+            for i in 0..3 {
                 let cycle_count = i + 1;
-                let timestamp =
-                    ((ctx.metadata().block_time().millis + CYCLE_DURATION) * cycle_count).into();
+                let lp_amount = linear_release(owner_lp_allocated, 3, cycle_count)
+                    - linear_release(owner_lp_allocated, 3, i);
 
-                holder_info.insert_locked_cycle(
+                let _ = launch_pad.locked_release.insert(
                     cycle_count as u8,
-                    lpt_amount,
-                    exchange.lp_token_id,
-                    timestamp,
+                    (
+                        lp_amount,
+                        lp_token_id,
+                        (cliff.millis + CYCLE_DURATION * 4 * cycle_count).into(),
+                        false,
+                    ),
                 );
             }
         }
 
-        let mut launch_pad = host.state_mut().get_mut_launchpad(product_name).unwrap();
-
-        // Pre-computing the release cycle information for the product owner
-        // locked funds release (LPTokens). For product owner, locked funds
-        // are released over a year from now in 3 cycles which are equally
-        // separated by 4 months interval.
-        // This is all aligned with the platform's policies to prevent rug-pull
-        // as much as possible.
-        for i in 0..3 {
-            let cycle_count = i + 1;
-            let lp_amount: TokenAmount = (lp_allocated.0 / 3).into();
-
-            let _ = launch_pad.locked_release.insert(
-                cycle_count as u8,
-                (
-                    lp_amount,
-                    exchange.lp_token_id,
-                    ((ctx.metadata().block_time().millis + CYCLE_DURATION * 4) * cycle_count)
-                        .into(),
-                    false,
-                ),
-            );
-        }
-
-        return Ok(());
-    }
+        return Ok(());
+    }
 
     Err(Error::Claimed)
 }
@@ -805,27 +1241,70 @@ fn withdraw_raised(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResu
     name = "WithDrawLockedFunds",
     mutable,
     parameter = "ClaimLockedParams",
-    error = "Error"
+    error = "Error",
+    enable_logger
 )]
-fn withdraw_locked_funds(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+fn withdraw_locked_funds(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    let claim_params: ClaimLockedParams = ctx.parameter_cursor().get()?;
+
+    // LPTokens are held in the DEX where the launch pad locked its liquidity
+    let dex_contract = host
+        .state()
+        .get_launchpad(claim_params.launch_pad.clone())?
+        .dex;
+
+    withdraw_locked_funds_with(
+        ctx,
+        host,
+        logger,
+        claim_params,
+        &mut DexClient::new(dex_contract),
+    )
+}
+
+/// Transfers the LPTokens released in the cycle to the claimer from the venue
+/// where the liquidity of the launch pad is locked.
+fn withdraw_locked_funds_with<S, H, V>(
+    ctx: &impl HasReceiveContext,
+    host: &mut H,
+    logger: &mut impl HasLogger,
+    claim_params: ClaimLockedParams,
+    venue: &mut V,
+) -> ContractResult<()>
+where
+    S: HasStateApi,
+    H: HasHost<State<S>, StateApiType = S>,
+    V: LiquidityVenue<H>,
+{
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     let sender = match ctx.sender() {
         Address::Account(acc) => acc,
         Address::Contract(_) => bail!(Error::OnlyAccount),
     };
 
-    let claim_params: ClaimLockedParams = ctx.parameter_cursor().get()?;
+    let launch_pad = host.state().get_launchpad(claim_params.launch_pad)?;
+    let id = launch_pad.id;
+    let product_name = launch_pad.product_name();
+    drop(launch_pad);
 
-    let (tokend_id, token_amount) = match claim_params.claimer {
+    let (tokend_id, token_amount, cycle) = match claim_params.claimer {
         Claimer::OWNER(cycle) => {
-            let launch_pad = host
-                .state()
-                .get_launchpad(claim_params.product_name.clone())?;
+            let launch_pad = host.state().get_launchpad(id.into())?;
 
             ensure!(
                 sender == launch_pad.get_product_owner(),
                 Error::UnAuthorized
             );
 
+            // Same as the holders, owner can not claim during the cliff period
+            launch_pad.ensure_cliff_elapsed(ctx.metadata().block_time())?;
+
             if let Some(cycle_details) = launch_pad.locked_release.get(&cycle) {
                 let (token_amount, lp_token_id, timestamp, claimed) = *cycle_details;
 
@@ -833,48 +1312,68 @@ fn withdraw_locked_funds(ctx: &ReceiveContext, host: &mut Host<State>) -> Contra
                 ensure!(ctx.metadata().block_time() >= timestamp, Error::NotElapsed);
 
                 host.state_mut()
-                    .get_mut_launchpad(claim_params.product_name)?
+                    .get_mut_launchpad(id.into())?
                     .set_locked_release_info(cycle, true);
 
-                (lp_token_id, token_amount)
+                (lp_token_id, token_amount, cycle)
             } else {
                 return Err(Error::InCorrect);
             }
         }
         Claimer::HOLDER(cycle) => {
-            if let Some(locked_cycle_details) = host
-                .state()
-                .get_launchpad(claim_params.product_name.clone())?
-                .get_holder_info(sender)?
-                .release_data
-                .locked
-                .get(&cycle)
-            {
-                let (token_amount, lp_token_id, timestamp, claimed) = *locked_cycle_details;
+            let launch_pad = host.state().get_launchpad(id.into())?;
+
+            // Holders can not claim during the cliff period
+            launch_pad.ensure_cliff_elapsed(ctx.metadata().block_time())?;
+
+            // Return early with error if the cycle does not exist, or no
+            // liquidity is locked for the holders
+            let schedule = launch_pad
+                .release_schedule
+                .as_ref()
+                .ok_or(Error::InCorrect)?;
+            let (lp_token_id, _) = schedule.liquidity.ok_or(Error::InCorrect)?;
+            let cycle_count = cycle as Months;
+            ensure!(
+                cycle_count > 0 && cycle_count <= schedule.lp_cycles,
+                Error::InCorrect
+            );
 
-                ensure!(!claimed, Error::Claimed);
-                ensure!(ctx.metadata().block_time() >= timestamp, Error::NotElapsed);
+            ensure!(
+                ctx.metadata().block_time() >= schedule.cycle_time(cycle_count),
+                Error::NotElapsed
+            );
 
-                host.state_mut()
-                    .get_mut_launchpad(claim_params.product_name)?
-                    .set_holder_locked_release_info(sender, cycle, true);
+            drop(launch_pad);
 
-                (lp_token_id, token_amount)
-            } else {
-                return Err(Error::InCorrect);
-            }
+            let token_amount = host
+                .state_mut()
+                .get_mut_launchpad(id.into())?
+                .claim_lp_tokens(sender, cycle_count)?
+                .ok_or(Error::Claimed)?;
+
+            (lp_token_id, token_amount, cycle)
         }
     };
 
-    DexClient::new(host.state().dex_address()).transfer(
+    logger.log(&Event::LOCKEDCLAIMED(LockedClaimEvent {
+        launchpad_id: id,
+        launchpad_name: product_name,
+        claimer: sender,
+        cycle,
+        lp_token_id: tokend_id,
+        amount: token_amount,
+    }))?;
+
+    venue.transfer_lp_tokens(
         host,
-        TransferParams(vec![Transfer {
+        vec![Transfer {
             token_id: tokend_id,
             amount: token_amount,
             from: ctx.self_address().into(),
             to: concordium_cis2::Receiver::Account(sender),
             data: AdditionalData::empty(),
-        }]),
+        }],
     )?;
     // let result = host.invoke_contract(
     //     &host.state().dex_address(),
@@ -899,25 +1398,198 @@ fn withdraw_locked_funds(ctx: &ReceiveContext, host: &mut Host<State>) -> Contra
     Ok(())
 }
 
+#[receive(
+    contract = "LaunchPad",
+    name = "ClaimAll",
+    mutable,
+    parameter = "ClaimAllParams",
+    error = "Error",
+    enable_logger
+)]
+fn claim_all(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    claim_all_with(ctx, host, logger, DexClient::new)
+}
+
+/// Claims the tokens and LPTokens released to the holder across the launch
+/// pads, with the LPTokens transferred from the venue each launch pad locked
+/// its liquidity in. Launch pads still in their cliff, or without a release
+/// schedule yet, are skipped rather than failing the whole batch.
+fn claim_all_with<S, H, V>(
+    ctx: &impl HasReceiveContext,
+    host: &mut H,
+    logger: &mut impl HasLogger,
+    venue_of: impl Fn(ContractAddress) -> V,
+) -> ContractResult<()>
+where
+    S: HasStateApi,
+    H: HasHost<State<S>, StateApiType = S>,
+    V: LiquidityVenue<H>,
+{
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
+    // Only Account is supposed to invoke this method
+    let holder = match ctx.sender() {
+        Address::Account(acc) => acc,
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+    };
+
+    // Reading the IDs or product names to identify the launch pads
+    let params: ClaimAllParams = ctx.parameter_cursor().get()?;
+    let current = ctx.metadata().block_time();
+
+    // Claimed tokens accumulated per token of each CIS2 contract, and
+    // claimed LP tokens accumulated per LP token of each DEX, to be
+    // settled together
+    let mut token_transfers: Vec<(ContractAddress, TokenIdVec, TokenAmount)> = Vec::new();
+    let mut lp_transfers: Vec<(ContractAddress, TokenIdU64, TokenAmount)> = Vec::new();
+
+    for key in params.launch_pads {
+        let launch_pad = host.state().get_launchpad(key)?;
+
+        // Make sure that the launch pad is not canceled and is finished
+        ensure!(
+            !launch_pad.is_canceled() && launch_pad.is_finished(ctx),
+            Error::JobFailed
+        );
+
+        let id = launch_pad.id;
+        let cis2_contract = launch_pad.get_cis2_contract();
+        let token_id = launch_pad.get_product_token_id();
+        let dex_contract = launch_pad.dex;
+
+        // Make sure that the sender is a holder of the launch pad
+        launch_pad.get_holder_info(holder)?;
+
+        // Nothing is released until the cliff duration has elapsed, the
+        // raised funds are withdrawn and the release schedule is set. Such
+        // launch pads are skipped so as not to block the claims from the
+        // matured ones
+        if launch_pad.ensure_cliff_elapsed(current).is_err() {
+            continue;
+        }
+        let schedule = match launch_pad.release_schedule.clone() {
+            Some(schedule) => schedule,
+            None => continue,
+        };
+
+        // Amounts released until the last elapsed cycles, which are not
+        // yet claimed by the holder
+        let cycle = schedule.elapsed_cycles(schedule.cycles, current);
+        let lp_cycle = schedule.elapsed_cycles(schedule.lp_cycles, current);
+        drop(launch_pad);
+
+        let mut launch_pad = host.state_mut().get_mut_launchpad(id.into())?;
+        let token_amount = launch_pad
+            .claim_tokens(holder, cycle)?
+            .unwrap_or_else(|| 0.into());
+        let lp_amount = launch_pad
+            .claim_lp_tokens(holder, lp_cycle)?
+            .unwrap_or_else(|| 0.into());
+
+        if token_amount > 0.into() {
+            launch_pad.token_balance -= token_amount;
+
+            match token_transfers
+                .iter_mut()
+                .find(|(contract, id, _)| *contract == cis2_contract && *id == token_id)
+            {
+                Some(transfer) => transfer.2 += token_amount,
+                None => token_transfers.push((cis2_contract, token_id, token_amount)),
+            }
+
+            logger.log(&Event::CLAIMED(ClaimEvent {
+                launchpad_id: launch_pad.id,
+                launchpad_name: launch_pad.product_name(),
+                holder,
+                cycle: cycle as u8,
+                amount: token_amount,
+            }))?;
+        }
+
+        if let Some((lp_token_id, _)) = schedule.liquidity.filter(|_| lp_amount > 0.into()) {
+            match lp_transfers
+                .iter_mut()
+                .find(|(dex, id, _)| *dex == dex_contract && *id == lp_token_id)
+            {
+                Some(transfer) => transfer.2 += lp_amount,
+                None => lp_transfers.push((dex_contract, lp_token_id, lp_amount)),
+            }
+
+            logger.log(&Event::LOCKEDCLAIMED(LockedClaimEvent {
+                launchpad_id: launch_pad.id,
+                launchpad_name: launch_pad.product_name(),
+                claimer: holder,
+                cycle: lp_cycle as u8,
+                lp_token_id,
+                amount: lp_amount,
+            }))?;
+        }
+    }
+
+    // Return early with error if none of the cycles has elapsed, including
+    // when every launch pad is still in its cliff
+    ensure!(
+        !token_transfers.is_empty() || !lp_transfers.is_empty(),
+        Error::NotElapsed
+    );
+
+    for (cis2_contract, token_id, amount) in token_transfers {
+        Cis2Client::new(cis2_contract).transfer(
+            host,
+            Transfer {
+                token_id,
+                amount,
+                from: ctx.self_address().into(),
+                to: holder.into(),
+                data: AdditionalData::empty(),
+            },
+        )?;
+    }
+
+    for (dex_contract, token_id, amount) in lp_transfers {
+        venue_of(dex_contract).transfer_lp_tokens(
+            host,
+            vec![Transfer {
+                token_id,
+                amount,
+                from: ctx.self_address().into(),
+                to: concordium_cis2::Receiver::Account(holder),
+                data: AdditionalData::empty(),
+            }],
+        )?;
+    }
+
+    Ok(())
+}
+
 #[receive(
     contract = "LaunchPad",
     name = "CancelLaunchPad",
     mutable,
-    parameter = "String",
-    error = "Error"
+    parameter = "LaunchPadKey",
+    error = "Error",
+    enable_logger
 )]
-fn cancel(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+fn cancel(ctx: &ReceiveContext, host: &mut Host<State>, logger: &mut Logger) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
     // Only Account is supposed to invoke this method
     let owner = match ctx.sender() {
         Address::Account(acc) => acc,
         Address::Contract(_) => bail!(Error::OnlyAccount),
     };
 
-    // Reading the product name to identify the launch pad
-    let product_name: ProductName = ctx.parameter_cursor().get()?;
+    // Reading the ID or product name to identify the launch pad
+    let key: LaunchPadKey = ctx.parameter_cursor().get()?;
 
-    // Getting the launch pad from state identified by the product name
-    let launch_pad = host.state().get_launchpad(product_name.clone())?;
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(key)?;
 
     // Make sure that the transaction is authorized
     ensure!(owner == launch_pad.get_product_owner(), Error::UnAuthorized);
@@ -929,67 +1601,809 @@ fn cancel(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
         Error::JobFailed
     );
 
-    host.state_mut()
-        .get_mut_launchpad(product_name)
-        .unwrap()
-        .status = Status::CANCELED;
+    let fee = launch_pad.cancel();
+
+    logger.log(&Event::CANCELED(CancelEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        by: owner,
+        forced: false,
+    }))?;
+
+    drop(launch_pad);
+
+    // Refunding the registeration fee of the launch pad canceled
+    // while in review
+    if fee > Amount::zero() {
+        host.invoke_transfer(&owner, fee)?;
+    }
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "ForceCancel",
+    mutable,
+    parameter = "LaunchPadKey",
+    error = "Error",
+    enable_logger
+)]
+fn force_cancel(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only the admins are allowed to forcibly cancel a launch pad
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
+
+    // Reading the ID or product name to identify the launch pad
+    let key: LaunchPadKey = ctx.parameter_cursor().get()?;
+
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(key)?;
+
+    // Unlike the owner, admin is allowed to cancel even after the soft
+    // cap is reached, as long as the raised funds are not withdrawn
+    ensure!(
+        !launch_pad.is_canceled()
+            && !launch_pad.is_completed()
+            && !launch_pad.is_rejected()
+            && !launch_pad.withdrawn,
+        Error::JobFailed
+    );
+
+    // Every holder is now allowed to get the investment refunded, while
+    // the product tokens deposited by the owner are frozen for review
+    let fee = launch_pad.cancel();
+    launch_pad.frozen = true;
+
+    logger.log(&Event::CANCELED(CancelEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        by: ctx.invoker(),
+        forced: true,
+    }))?;
+
+    let owner = launch_pad.get_product_owner();
+    drop(launch_pad);
+
+    // Refunding the registeration fee to the owner of the launch pad
+    // canceled while in review
+    if fee > Amount::zero() {
+        host.invoke_transfer(&owner, fee)?;
+    }
 
     Ok(())
 }
 
 #[receive(
     contract = "LaunchPad",
-    name = "viewState",
-    return_value = "StateView",
+    name = "UnfreezeTokens",
+    mutable,
+    parameter = "LaunchPadKey",
     error = "Error"
 )]
-fn view_state(_: &ReceiveContext, host: &Host<State>) -> ContractResult<StateView> {
-    let state = host.state();
+fn unfreeze_tokens(ctx: &ReceiveContext, host: &mut Host<State>) -> ContractResult<()> {
+    // Only the admins are allowed to unfreeze the product tokens once
+    // the review is concluded
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
 
-    let state_view = StateView {
-        launch_pads: state.launchpads.iter().map(|(_, lp)| lp.into()).collect(),
-        investors: state
-            .investors
-            .iter()
-            .map(|(inv, lps)| (*inv, lps.clone()))
-            .collect(),
-        admin_info: state.admin.clone(),
-        total_launch_pads: state.counter,
-    };
+    // Reading the ID or product name to identify the launch pad
+    let key: LaunchPadKey = ctx.parameter_cursor().get()?;
 
-    Ok(state_view)
+    let mut launch_pad = host.state_mut().get_mut_launchpad(key)?;
+    ensure!(launch_pad.frozen, Error::JobFailed);
+
+    // Owner is allowed to reclaim the tokens afterwards
+    launch_pad.frozen = false;
+
+    Ok(())
 }
 
 #[receive(
     contract = "LaunchPad",
-    name = "viewAllLaunchPads",
-    return_value = "AllLaunchPads",
-    error = "Error"
+    name = "RefundInvestment",
+    mutable,
+    parameter = "LaunchPadKey",
+    error = "Error",
+    enable_logger
 )]
-fn view_all_launch_pads(_: &ReceiveContext, host: &Host<State>) -> ContractResult<AllLaunchPads> {
-    Ok(AllLaunchPads {
-        total_launch_pads: host.state().counter,
-        launch_pads: host
-            .state()
-            .launchpads
-            .iter()
-            .map(|(_, launch_pad)| launch_pad.into())
-            .collect(),
-    })
+fn refund_investment(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
+
+    // Only Account is supposed to invoke this method
+    let holder = match ctx.sender() {
+        Address::Account(acc) => acc,
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+    };
+
+    // Reading the ID or product name to identify the launch pad
+    let key: LaunchPadKey = ctx.parameter_cursor().get()?;
+
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(key)?;
+
+    // Investments are only refunded if the launch pad is canceled
+    // or the vesting has finished without reaching the soft cap
+    ensure!(launch_pad.is_refundable(ctx), Error::JobFailed);
+
+    // Marking the holder as refunded and reverting its contribution
+    // from the collected funds of the launch pad
+    let invested = launch_pad.refund_holder(holder)?;
+    let payment_token = launch_pad.payment_token.clone();
+
+    logger.log(&Event::REFUNDED(RefundEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        holder,
+        amount: invested,
+    }))?;
+
+    drop(launch_pad);
+
+    // Transfering the invested amount back to the holder in CCD
+    // or in the payment token of the launch pad
+    transfer_funds(
+        host,
+        ctx.self_address(),
+        holder,
+        Funds::new(payment_token, invested),
+    )?;
+
+    Ok(())
 }
 
 #[receive(
     contract = "LaunchPad",
-    name = "viewLaunchPad",
-    parameter = "String",
-    return_value = "LaunchPadView",
-    error = "Error"
+    name = "ReclaimTokens",
+    mutable,
+    parameter = "LaunchPadKey",
+    error = "Error",
+    enable_logger
 )]
-fn view_launch_pad(ctx: &ReceiveContext, host: &Host<State>) -> ContractResult<LaunchPadView> {
-    let product_name: ProductName = ctx.parameter_cursor().get()?;
-    let inner_state = host.state().get_launchpad(product_name)?;
+fn reclaim_tokens(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Ensure that the platform is not paused by the admin
+    host.state().ensure_not_paused()?;
 
-    Ok(inner_state.into())
+    // Only Account is supposed to invoke this method
+    let owner = match ctx.sender() {
+        Address::Account(acc) => acc,
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+    };
+
+    // Reading the ID or product name to identify the launch pad
+    let key: LaunchPadKey = ctx.parameter_cursor().get()?;
+
+    // Getting the launch pad from state identified by the ID or product name
+    let mut launch_pad = host.state_mut().get_mut_launchpad(key)?;
+
+    // Make sure that the transaction is authorized
+    ensure!(owner == launch_pad.get_product_owner(), Error::UnAuthorized);
+
+    // Tokens reserved for the holders and the liquidity are never
+    // reclaimable, it is either the complete balance in case of a
+    // failed launch pad or the unsold tokens after a successful one
+    let amount = launch_pad.reclaimable_tokens(ctx)?;
+    ensure!(amount > 0.into(), Error::Claimed);
+
+    // Unsold tokens may be reclaimed before the raised funds are withdrawn
+    log_cliff_started(ctx, &mut launch_pad, logger)?;
+
+    launch_pad.token_balance -= amount;
+    launch_pad.available_tokens = if launch_pad.available_tokens > amount {
+        launch_pad.available_tokens - amount
+    } else {
+        0.into()
+    };
+
+    logger.log(&Event::RECLAIMED(ReclaimEvent {
+        launchpad_id: launch_pad.id,
+        launchpad_name: launch_pad.product_name(),
+        owner,
+        amount,
+    }))?;
+
+    let cis2_contract = launch_pad.get_cis2_contract();
+    let token_id = launch_pad.get_product_token_id();
+
+    drop(launch_pad);
+
+    // Transfering the reclaimable tokens back to the product owner
+    Cis2Client::new(cis2_contract).transfer(
+        host,
+        Transfer {
+            token_id,
+            amount,
+            from: ctx.self_address().into(),
+            to: owner.into(),
+            data: AdditionalData::empty(),
+        },
+    )?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "UpdateRegisterationFee",
+    mutable,
+    parameter = "Amount",
+    error = "Error",
+    enable_logger
+)]
+fn update_registeration_fee(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only treasurer is allowed to update the platform fee and shares
+    host.state().ensure_role(ctx.sender(), Role::Treasurer)?;
+
+    let fee: Amount = ctx.parameter_cursor().get()?;
+
+    host.state_mut().admin.registeration_fee = fee;
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::RegisterationFee(fee),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "UpdateAllocationShare",
+    mutable,
+    parameter = "u64",
+    error = "Error",
+    enable_logger
+)]
+fn update_allocation_share(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only treasurer is allowed to update the platform fee and shares
+    host.state().ensure_role(ctx.sender(), Role::Treasurer)?;
+
+    let share: u64 = ctx.parameter_cursor().get()?;
+
+    // Share is a percentage and can never exceed 100%
+    ensure!(share <= MAX_SHARE, Error::InCorrect);
+
+    host.state_mut().admin.allocation_share = share;
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::AllocationShare(share),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "UpdateLiquidityShare",
+    mutable,
+    parameter = "u64",
+    error = "Error",
+    enable_logger
+)]
+fn update_liquidity_share(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only treasurer is allowed to update the platform fee and shares
+    host.state().ensure_role(ctx.sender(), Role::Treasurer)?;
+
+    let share: u64 = ctx.parameter_cursor().get()?;
+
+    // Share is a percentage and can never exceed 100%
+    ensure!(share <= MAX_SHARE, Error::InCorrect);
+
+    host.state_mut().admin.liquidity_share = share;
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::LiquidityShare(share),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "UpdateDexAddress",
+    mutable,
+    parameter = "ContractAddress",
+    error = "Error",
+    enable_logger
+)]
+fn update_dex_address(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only admin role is allowed to update the platform DEX
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
+
+    let dex_address: ContractAddress = ctx.parameter_cursor().get()?;
+
+    host.state_mut().admin.dex_address = dex_address;
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::DexAddress(dex_address),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "RegisterDex",
+    mutable,
+    parameter = "ContractAddress",
+    error = "Error",
+    enable_logger
+)]
+fn register_dex(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only admin role is allowed to register the DEX contracts
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
+
+    let dex: ContractAddress = ctx.parameter_cursor().get()?;
+
+    // Return early with error if the DEX is already available
+    ensure!(!host.state().is_dex_registered(&dex), Error::Taken);
+
+    host.state_mut().dexes.push(dex);
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::DexRegistered(dex),
+    }))?;
+
+    Ok(())
+}
+
+/// Unregisters the DEX contract, so that it can not be picked by the
+/// launch pads created afterwards. Launch pads which already picked the
+/// DEX keep their liquidity and LPTokens in it.
+#[receive(
+    contract = "LaunchPad",
+    name = "UnregisterDex",
+    mutable,
+    parameter = "ContractAddress",
+    error = "Error",
+    enable_logger
+)]
+fn unregister_dex(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only admin role is allowed to unregister the DEX contracts
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
+
+    let dex: ContractAddress = ctx.parameter_cursor().get()?;
+
+    let dexes = &mut host.state_mut().dexes;
+    let index = dexes
+        .iter()
+        .position(|registered| *registered == dex)
+        .ok_or(Error::UnknownDex)?;
+    dexes.remove(index);
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::DexUnregistered(dex),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "ProposeAdmin",
+    mutable,
+    parameter = "AccountAddress",
+    error = "Error",
+    enable_logger
+)]
+fn propose_admin(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only admin is allowed to hand over the admin role
+    host.state().ensure_admin(ctx.sender())?;
+
+    let proposed: AccountAddress = ctx.parameter_cursor().get()?;
+
+    // Admin role is not handed over until the proposed account
+    // accepts it, which prevents locking out the admin by handing
+    // it over to a wrong account
+    host.state_mut().pending_admin = Some(proposed);
+
+    logger.log(&Event::ADMINPROPOSED(AdminProposeEvent {
+        current: host.state().admin_address(),
+        proposed,
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "AcceptAdmin",
+    mutable,
+    error = "Error",
+    enable_logger
+)]
+fn accept_admin(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only the proposed account is allowed to accept the admin role
+    let proposed = match host.state().pending_admin {
+        Some(proposed) => proposed,
+        None => bail!(Error::NotFound),
+    };
+    ensure!(ctx.sender().matches_account(&proposed), Error::UnAuthorized);
+
+    let state = host.state_mut();
+    state.admin.address = proposed;
+    state.pending_admin = None;
+
+    logger.log(&Event::ADMINUPDATED(AdminUpdateEvent {
+        update: AdminUpdate::Address(proposed),
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "GrantRole",
+    mutable,
+    parameter = "RoleParams",
+    error = "Error",
+    enable_logger
+)]
+fn grant_role(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only admin role is allowed to manage roles
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
+
+    let params: RoleParams = ctx.parameter_cursor().get()?;
+
+    match host.state_mut().roles.entry(params.account) {
+        // Insert the new account to the state with the role
+        Entry::Vacant(entry) => {
+            entry.insert(vec![params.role]);
+        }
+        // Update the existing account in the state with the role,
+        // if the role is not already granted
+        Entry::Occupied(mut entry) => {
+            ensure!(!entry.contains(&params.role), Error::Taken);
+            entry.modify(|roles| roles.push(params.role));
+        }
+    }
+
+    logger.log(&Event::ROLEGRANTED(RoleEvent {
+        account: params.account,
+        role: params.role,
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "RevokeRole",
+    mutable,
+    parameter = "RoleParams",
+    error = "Error",
+    enable_logger
+)]
+fn revoke_role(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only admin role is allowed to manage roles
+    host.state().ensure_role(ctx.sender(), Role::Admin)?;
+
+    let params: RoleParams = ctx.parameter_cursor().get()?;
+
+    let remaining = match host.state_mut().roles.get_mut(&params.account) {
+        Some(mut roles) => {
+            ensure!(roles.contains(&params.role), Error::NotFound);
+            roles.retain(|role| *role != params.role);
+            roles.len()
+        }
+        None => bail!(Error::NotFound),
+    };
+
+    // Removing the account from the state if it holds no more roles
+    if remaining == 0 {
+        host.state_mut().roles.remove(&params.account);
+    }
+
+    logger.log(&Event::ROLEREVOKED(RoleEvent {
+        account: params.account,
+        role: params.role,
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "PausePlatform",
+    mutable,
+    parameter = "String",
+    error = "Error",
+    enable_logger
+)]
+fn pause_platform(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only pauser is allowed to pause the platform in case of emergency
+    host.state().ensure_role(ctx.sender(), Role::Pauser)?;
+
+    // Reason of the pause, such as the exploit discovered
+    let reason: String = ctx.parameter_cursor().get()?;
+
+    // Ensure that the platform is not already paused
+    host.state().ensure_not_paused()?;
+
+    host.state_mut().pause_reason = Some(reason.clone());
+
+    logger.log(&Event::PLATFORMPAUSED(PlatformPauseEvent {
+        by: ctx.invoker(),
+        reason,
+    }))?;
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "ResumePlatform",
+    mutable,
+    error = "Error",
+    enable_logger
+)]
+fn resume_platform(
+    ctx: &ReceiveContext,
+    host: &mut Host<State>,
+    logger: &mut Logger,
+) -> ContractResult<()> {
+    // Only the platform admin is allowed to resume the platform
+    // once the emergency is resolved
+    host.state().ensure_admin(ctx.sender())?;
+
+    // Ensure that the platform is paused
+    ensure!(host.state().pause_reason.is_some(), Error::JobFailed);
+
+    host.state_mut().pause_reason = None;
+
+    logger.log(&Event::PLATFORMRESUMED(PlatformResumeEvent {
+        by: ctx.invoker(),
+    }))?;
+
+    Ok(())
+}
+
+/// Upgrades the contract to the given module using the native module
+/// upgrade, keeping the address, balance and state of the contract. Only
+/// the platform admin is allowed to upgrade.
+///
+/// Optionally invokes the given migration entry point on the upgraded
+/// contract, which is expected to read the state `version` and migrate
+/// the older layouts to the new ones.
+///
+/// This is a low level entry point, so that the state is not written
+/// back in the old layout after the migration.
+#[receive(
+    contract = "LaunchPad",
+    name = "upgrade",
+    parameter = "UpgradeParams",
+    error = "Error",
+    low_level
+)]
+fn upgrade(ctx: &ReceiveContext, host: &mut LowLevelHost) -> ContractResult<()> {
+    // Reading the top level state to authorize the sender
+    let state: State = host.state().read_root()?;
+    state.ensure_admin(ctx.sender())?;
+
+    let params: UpgradeParams = ctx.parameter_cursor().get()?;
+
+    // Upgrading the module, the new module takes effect for the
+    // subsequent invocations
+    host.upgrade(params.module)?;
+
+    // Invoking the migration entry point on the upgraded contract
+    if let Some((entrypoint, parameter)) = params.migrate {
+        host.invoke_contract_raw(
+            &ctx.self_address(),
+            parameter.as_parameter(),
+            entrypoint.as_entrypoint_name(),
+            Amount::zero(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "viewState",
+    return_value = "StateView",
+    error = "Error"
+)]
+fn view_state(_: &ReceiveContext, host: &Host<State>) -> ContractResult<StateView> {
+    let state = host.state();
+
+    let state_view = StateView {
+        launch_pads: state.launchpads.iter().map(|(_, lp)| lp.into()).collect(),
+        investors: state
+            .investors
+            .iter()
+            .map(|(inv, lps)| (*inv, lps.clone()))
+            .collect(),
+        admin_info: state.admin.clone(),
+        pending_admin: state.pending_admin,
+        roles: state
+            .roles
+            .iter()
+            .map(|(acc, roles)| (*acc, roles.clone()))
+            .collect(),
+        total_launch_pads: state.counter,
+        version: state.version,
+        pause_reason: state.pause_reason.clone(),
+        dexes: state.dexes.clone(),
+    };
+
+    Ok(state_view)
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "viewAllLaunchPads",
+    return_value = "AllLaunchPads",
+    error = "Error"
+)]
+fn view_all_launch_pads(_: &ReceiveContext, host: &Host<State>) -> ContractResult<AllLaunchPads> {
+    Ok(AllLaunchPads {
+        total_launch_pads: host.state().counter,
+        launch_pads: host
+            .state()
+            .launchpads
+            .iter()
+            .map(|(_, launch_pad)| launch_pad.into())
+            .collect(),
+    })
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "viewLaunchPadsPage",
+    parameter = "ListParams",
+    return_value = "LaunchPadsPage",
+    error = "Error"
+)]
+fn view_launch_pads_page(
+    ctx: &ReceiveContext,
+    host: &Host<State>,
+) -> ContractResult<LaunchPadsPage> {
+    let params: ListParams = ctx.parameter_cursor().get()?;
+
+    // Ensure that the page size is within the limit, so that the
+    // response fits in the energy and return value limits
+    ensure!(
+        params.limit > 0 && params.limit <= MAX_PAGE_LIMIT,
+        Error::Limit
+    );
+
+    // Launch pads are listed in the order of their IDs, starting from
+    // the cursor, so that reading a page does not depend on the number of
+    // launch pads listed before it, and the pages are stable while new
+    // launch pads are created. Next matching launch pad beyond the limit
+    // is the cursor of the next page.
+    let state = host.state();
+    let mut launch_pads: Vec<LaunchPadSummary> = Vec::new();
+    let mut next_id = None;
+
+    for id in params.from..state.counter {
+        let launch_pad = match state.launchpads.get(&id) {
+            Some(launch_pad) => launch_pad,
+            None => continue,
+        };
+
+        if params
+            .status
+            .as_ref()
+            .is_some_and(|status| launch_pad.status != *status)
+        {
+            continue;
+        }
+
+        if launch_pads.len() == params.limit as usize {
+            next_id = Some(id);
+            break;
+        }
+
+        launch_pads.push(launch_pad.into());
+    }
+
+    Ok(LaunchPadsPage {
+        launch_pads,
+        next_id,
+    })
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "viewLaunchPad",
+    parameter = "LaunchPadKey",
+    return_value = "LaunchPadView",
+    error = "Error"
+)]
+fn view_launch_pad(ctx: &ReceiveContext, host: &Host<State>) -> ContractResult<LaunchPadView> {
+    let key: LaunchPadKey = ctx.parameter_cursor().get()?;
+    let inner_state = host.state().get_launchpad(key)?;
+
+    Ok(inner_state.into())
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "viewHolderPosition",
+    parameter = "HolderPositionParams",
+    return_value = "HolderPosition",
+    error = "Error"
+)]
+fn view_holder_position(
+    ctx: &ReceiveContext,
+    host: &Host<State>,
+) -> ContractResult<HolderPosition> {
+    let params: HolderPositionParams = ctx.parameter_cursor().get()?;
+    let launch_pad = host.state().get_launchpad(params.launch_pad)?;
+    let holder_info = launch_pad.get_holder_info(params.account)?;
+
+    Ok(HolderPosition::new(
+        &launch_pad,
+        &holder_info,
+        ctx.metadata().block_time(),
+    ))
+}
+
+#[receive(
+    contract = "LaunchPad",
+    name = "viewPauseReason",
+    return_value = "Option<String>",
+    error = "Error"
+)]
+fn view_pause_reason(_: &ReceiveContext, host: &Host<State>) -> ContractResult<Option<String>> {
+    Ok(host.state().pause_reason.clone())
 }
 
 #[receive(
@@ -1010,6 +2424,6 @@ fn view_my_launch_pads(ctx: &ReceiveContext, host: &Host<State>) -> ContractResu
     // returning the serialized result
     Ok(ids
         .iter()
-        .map(|id| host.state().get_launchpad(id.clone()).unwrap().into())
+        .map(|id| host.state().get_launchpad((*id).into()).unwrap().into())
         .collect())
 }
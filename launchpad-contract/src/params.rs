@@ -1,9 +1,16 @@
 use crate::{
-    state::{Admin, LiquidityDetails, Product, TimePeriod, VestingLimits, DAYS},
-    ProductName,
+    errors::Error,
+    state::{
+        Admin, LiquidityDetails, PaymentToken, Product, Role, Status, TimePeriod, VestingLimits,
+        DAYS,
+    },
+    LaunchPadId, ProductName,
 };
 use concordium_cis2::TokenAmountU64 as TokenAmount;
-use concordium_std::{Amount, Deserial, Duration, SchemaType, Serial, Serialize, Timestamp};
+use concordium_std::{
+    ensure, AccountAddress, Amount, ContractAddress, Deserial, Duration, ModuleReference,
+    OwnedEntrypointName, OwnedParameter, SchemaType, Serial, Serialize, Timestamp,
+};
 
 pub type Months = u64;
 
@@ -37,13 +44,27 @@ pub struct CreateParams {
     pub lockup_details: LockupDetails,
     /// Token Liquidity information to lock the funds
     pub liquidity_details: LiquidityDetails,
+    /// Optional ending time of the allowlist phase, which starts along
+    /// with the presale. During this phase only the allowlisted accounts
+    /// are allowed to vest within their allocations
+    pub allowlist_end: Option<Timestamp>,
+    /// Ordered list of sale rounds, such as seed, private and public.
+    /// If empty, the presale runs as a single round based on the time
+    /// period, token price and vesting limits provided above
+    pub rounds: Vec<RoundParams>,
+    /// Optional CIS2 token, such as a stablecoin, accepted as payment
+    /// for investments instead of CCD
+    pub payment_token: Option<PaymentToken>,
+    /// Optional DEX contract, registered by the admin, to lock the
+    /// liquidity in instead of the platform DEX
+    pub dex: Option<ContractAddress>,
 }
 
 impl CreateParams {
     /// Getter function to get the provided time period
     /// of cliff
     pub fn cliff(&self) -> Duration {
-        Duration::from_days((self.lockup_details.cliff * DAYS) as u64)
+        Duration::from_days(self.lockup_details.cliff * DAYS)
     }
 
     /// Getter function to get the provided ending time
@@ -51,6 +72,111 @@ impl CreateParams {
     pub fn launchpad_end_time(&self) -> Timestamp {
         self.timeperiod.end
     }
+
+    /// Checks if the sale rounds, if provided, are ordered without
+    /// overlapping within the presale time period, and their allocations
+    /// does not exceed the allocated tokens of the product.
+    ///
+    /// Prices of the rounds can not decrease from one round to the next, so
+    /// that the tokens locked in liquidity at the price of the last round
+    /// never exceed the tokens sold.
+    pub fn is_rounds_valid(&self) -> bool {
+        let mut previous_end = self.timeperiod.start;
+        let mut previous_price = Amount::zero();
+        let mut allocated = TokenAmount::from(0);
+
+        for (index, round) in self.rounds.iter().enumerate() {
+            if round.timeperiod.start >= round.timeperiod.end
+                || (index > 0 && round.timeperiod.start <= previous_end)
+                || round.token_price < previous_price
+                || round.timeperiod.start < self.timeperiod.start
+                || round.timeperiod.end > self.timeperiod.end
+                || round.vest_limits.min > round.vest_limits.max
+            {
+                return false;
+            }
+
+            previous_end = round.timeperiod.end;
+            previous_price = round.token_price;
+            allocated += round.allocated_tokens;
+        }
+
+        self.rounds.len() <= u8::MAX as usize && allocated <= self.product.allocated_tokens
+    }
+
+    /// Ensures that the payment token, if provided, is acceptable. DEX
+    /// only supports pairs with CCD, hence liquidity in the payment token
+    /// is out of scope, and launch pads paid in a CIS2 token can not
+    /// allocate funds for liquidity
+    ///
+    /// Returns `Error::TokenLiquidity` otherwise
+    pub fn ensure_payment_valid(&self) -> Result<(), Error> {
+        ensure!(
+            self.payment_token.is_none() || self.liquidity_details.liquidity_allocation == 0,
+            Error::TokenLiquidity
+        );
+        Ok(())
+    }
+
+    /// Checks if the allowlist phase, if provided, ends within the
+    /// presale time period
+    pub fn is_allowlist_valid(&self) -> bool {
+        self.allowlist_end.map_or(true, |end| {
+            end > self.timeperiod.start && end <= self.timeperiod.end
+        })
+    }
+}
+
+/// Identifies a launch pad either by its sequential ID, or by the name of
+/// its product.
+#[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
+pub enum LaunchPadKey {
+    /// ID assigned to the launch pad at its creation
+    Id(LaunchPadId),
+    /// Name of the product for which the launch pad is created
+    Name(ProductName),
+}
+
+impl From<LaunchPadId> for LaunchPadKey {
+    fn from(value: LaunchPadId) -> Self {
+        Self::Id(value)
+    }
+}
+
+impl From<ProductName> for LaunchPadKey {
+    fn from(value: ProductName) -> Self {
+        Self::Name(value)
+    }
+}
+
+impl From<&str> for LaunchPadKey {
+    fn from(value: &str) -> Self {
+        Self::Name(value.to_string())
+    }
+}
+
+/// Typed payload to be passed as the additional data, along with the
+/// product tokens transfered to the `Deposit` entry point.
+///
+/// Payload is versioned, so that it can be extended without breaking
+/// the existing integrations.
+#[derive(Serialize, SchemaType)]
+pub enum DepositData {
+    /// Identifies the launch pad by the product name
+    V1 { product_name: ProductName },
+    /// Identifies the launch pad either by its ID or by the product name
+    V2 { launch_pad: LaunchPadKey },
+}
+
+impl DepositData {
+    /// Getter method to get the launch pad for which the tokens are
+    /// deposited.
+    pub fn launch_pad(self) -> LaunchPadKey {
+        match self {
+            Self::V1 { product_name } => product_name.into(),
+            Self::V2 { launch_pad } => launch_pad,
+        }
+    }
 }
 
 /// Lock up information to be provided by the user in `CreateLaunchPad`
@@ -66,9 +192,9 @@ pub struct LockupDetails {
 /// to approve or reject the Launch-pad
 #[derive(Serialize, SchemaType)]
 pub struct ApprovalParams {
-    /// Product name to uniquely identify the launch-pad
+    /// ID or product name to uniquely identify the launch-pad
     /// for approval
-    pub product_name: ProductName,
+    pub launch_pad: LaunchPadKey,
     /// A boolean if `true` means approved, if `false`
     /// mean rejected
     pub approve: bool,
@@ -78,8 +204,8 @@ pub struct ApprovalParams {
 /// launch-pad vesting
 #[derive(Serialize, SchemaType)]
 pub struct LivePauseParams {
-    /// Product name for unique launch-pad identification
-    pub poduct_name: ProductName,
+    /// ID or product name for unique launch-pad identification
+    pub launch_pad: LaunchPadKey,
     /// Duration for which the launch-pad is to be pause.
     /// It must be greater than 48 hrs
     pub pause_duration: TimePeriod,
@@ -88,11 +214,11 @@ pub struct LivePauseParams {
 }
 
 /// Parameters to be passed while invoking `Vest` to invest on a launch pad
-#[derive(Serialize, SchemaType)]
+#[derive(Serialize, SchemaType, Clone)]
 pub struct VestParams {
-    /// Product name to identify launch pad in contract
+    /// ID or product name to identify launch pad in contract
     /// state
-    pub product_name: ProductName,
+    pub launch_pad: LaunchPadKey,
     /// Amount of token to be bought from allocation
     /// in presale
     pub token_amount: TokenAmount,
@@ -101,6 +227,7 @@ pub struct VestParams {
 /// Defines who is claiming the locked tokens, either
 /// owner or holder, along with the cycle number.
 #[derive(Serial, Deserial, SchemaType)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Claimer {
     /// Product owner of the launch pad.
     OWNER(u8),
@@ -115,8 +242,8 @@ pub struct ClaimLockedParams {
     /// Indicates who is the claimer, owner/holder, along with
     /// the number of cycle to be claimed.
     pub claimer: Claimer,
-    /// Name of the product for launch pad identification.
-    pub product_name: ProductName,
+    /// ID or product name for launch pad identification.
+    pub launch_pad: LaunchPadKey,
 }
 
 /// Parameters to be passed while invoking `ClaimTokens` to claim the
@@ -125,6 +252,99 @@ pub struct ClaimLockedParams {
 pub struct ClaimUnLockedParams {
     /// Serial number of cycle to be claimed.
     pub cycle: u8,
-    /// Name of the product for launch pad identification.
-    pub product_name: ProductName,
+    /// ID or product name for launch pad identification.
+    pub launch_pad: LaunchPadKey,
+}
+
+/// Parameters to be passed while invoking `WithdrawFunds` by the product
+/// owner, bounding the liquidity added to the DEX from the raised funds.
+#[derive(Serialize, SchemaType)]
+pub struct WithdrawParams {
+    /// ID or product name for launch pad identification.
+    pub launch_pad: LaunchPadKey,
+    /// Minimum amount of LPTokens to be received for the liquidity
+    /// added to the DEX
+    pub min_lp_tokens: TokenAmount,
+    /// Maximum deviation, in basis points, of the price of the existing
+    /// liquidity pool from the base price of the product token
+    pub max_price_deviation: u64,
+}
+
+/// Parameters to be passed while invoking `ClaimAll` to claim every elapsed
+/// release cycle of the holder, unlocked and locked, in one go.
+#[derive(Serialize, SchemaType)]
+pub struct ClaimAllParams {
+    /// IDs or names of the products for launch pads identification.
+    pub launch_pads: Vec<LaunchPadKey>,
+}
+
+/// Parameters to be passed while invoking `GrantRole` or `RevokeRole` by
+/// the admin to manage the roles of an account.
+#[derive(Serialize, SchemaType)]
+pub struct RoleParams {
+    /// Account to which the role is granted or from which
+    /// the role is revoked
+    pub account: AccountAddress,
+    /// Role to be granted or revoked
+    pub role: Role,
+}
+
+/// Parameters to be passed while invoking `UpdateAllowlist` by the product
+/// owner to set the allocations of the allowlisted accounts.
+#[derive(Serialize, SchemaType)]
+pub struct AllowlistParams {
+    /// ID or product name for launch pad identification.
+    pub launch_pad: LaunchPadKey,
+    /// List of accounts with the amount of tokens allocated to them
+    /// for the allowlist phase, zero allocation removes the account
+    /// from the allowlist.
+    pub allocations: Vec<(AccountAddress, TokenAmount)>,
+}
+
+/// Details of a single sale round to be provided by the user in
+/// `CreateLaunchPad`
+#[derive(Serialize, SchemaType, Clone)]
+pub struct RoundParams {
+    /// Time window of the round
+    pub timeperiod: TimePeriod,
+    /// Per token price for the round
+    pub token_price: Amount,
+    /// Amount of tokens allocated for the round
+    pub allocated_tokens: TokenAmount,
+    /// Defines the maximum and minimum investment amounts acceptable
+    /// for the round
+    pub vest_limits: VestingLimits,
+}
+
+/// Parameters to be passed while invoking `upgrade` by the admin
+#[derive(Serialize, SchemaType)]
+pub struct UpgradeParams {
+    /// Reference of the deployed module to upgrade the contract to
+    pub module: ModuleReference,
+    /// Optional entry point, along with its parameter, to be invoked
+    /// on the upgraded contract to migrate the state
+    pub migrate: Option<(OwnedEntrypointName, OwnedParameter)>,
+}
+
+/// Parameters to be passed while invoking `viewLaunchPadsPage` to list
+/// the launch pads page by page
+#[derive(Serialize, SchemaType)]
+pub struct ListParams {
+    /// ID of the launch pad to start the page from, which is the `next_id`
+    /// of the previous page, or zero for the first page
+    pub from: LaunchPadId,
+    /// Maximum number of launch pads to be returned in the page
+    pub limit: u32,
+    /// Optional status to list only the launch pads in that status
+    pub status: Option<Status>,
+}
+
+/// Parameters to be passed while invoking `viewHolderPosition` to view the
+/// position of an account in a launch pad
+#[derive(Serialize, SchemaType)]
+pub struct HolderPositionParams {
+    /// ID or product name for launch pad identification.
+    pub launch_pad: LaunchPadKey,
+    /// Account of the holder whose position is to be viewed
+    pub account: AccountAddress,
 }
@@ -1,13 +1,15 @@
 use crate::{
     state::{
-        Admin, HolderInfo, LaunchPadState, LiquidityDetails, Lockup, Product, Status, VestingLimits,
+        Admin, HolderInfo, LaunchPad, LaunchPadState, LiquidityDetails, Lockup, PaymentToken,
+        Product, ReleaseSchedule, Role, Round, Status, TimePeriod, VestingLimits,
     },
-    ProductName,
+    LaunchPadId, ProductName,
 };
-use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU64};
+use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdVec};
 use concordium_std::{
     schema::{self, SchemaType},
-    AccountAddress, Amount, Deserial, SchemaType, Serial, Serialize, StateRef, Timestamp,
+    AccountAddress, Amount, ContractAddress, Deserial, MetadataUrl, SchemaType, Serial, Serialize,
+    StateRef, Timestamp,
 };
 
 /// Alias for the list of all launch-pads view.
@@ -18,9 +20,14 @@ pub type LaunchPadsView = Vec<LaunchPadView>;
 #[derive(Serial, Deserial, SchemaType, Debug)]
 pub struct StateView {
     pub launch_pads: LaunchPadsView,
-    pub investors: Vec<(AccountAddress, Vec<ProductName>)>,
+    pub investors: Vec<(AccountAddress, Vec<LaunchPadId>)>,
     pub admin_info: Admin,
+    pub pending_admin: Option<AccountAddress>,
+    pub roles: Vec<(AccountAddress, Vec<Role>)>,
     pub total_launch_pads: u32,
+    pub version: u32,
+    pub pause_reason: Option<String>,
+    pub dexes: Vec<ContractAddress>,
 }
 
 /// Defines the response to be returned to view all the launch
@@ -35,8 +42,10 @@ pub struct AllLaunchPads {
 /// regarding a launch-pad present in the contract.
 #[derive(Serialize, SchemaType, Debug)]
 pub struct LaunchPadView {
+    pub id: LaunchPadId,
     pub product: ProductView,
     pub raised: Amount,
+    pub sold_tokens: TokenAmount,
     pub status: Status,
     pub holders: Vec<(AccountAddress, HolderView)>,
     pub vest_limits: VestingLimits,
@@ -48,13 +57,22 @@ pub struct LaunchPadView {
     pub withdrawn: bool,
     pub lock_up: Lockup,
     pub liquidity_details: LiquidityDetails,
+    pub allowlist_end: Option<Timestamp>,
+    pub rounds: Vec<Round>,
+    pub payment_token: Option<PaymentToken>,
+    pub frozen: bool,
+    pub release_schedule: Option<ReleaseSchedule>,
+    pub dex: ContractAddress,
+    pub token_metadata: MetadataUrl,
 }
 
 impl From<LaunchPadState<'_>> for LaunchPadView {
     fn from(value: LaunchPadState<'_>) -> Self {
         Self {
+            id: value.id,
             product: value.product.clone().into(),
             raised: value.collected,
+            sold_tokens: value.sold_tokens,
             status: value.status.clone(),
             holders: value
                 .holders
@@ -79,6 +97,56 @@ impl From<LaunchPadState<'_>> for LaunchPadView {
             withdrawn: value.withdrawn,
             lock_up: value.lock_up.clone(),
             liquidity_details: value.liquidity_details.clone(),
+            allowlist_end: value.allowlist_end,
+            rounds: value.rounds.clone(),
+            payment_token: value.payment_token.clone(),
+            frozen: value.frozen,
+            release_schedule: value.release_schedule.clone(),
+            dex: value.dex,
+            token_metadata: value.token_metadata.clone(),
+        }
+    }
+}
+
+/// Defines the response to be returned to list the launch pads
+/// page by page.
+#[derive(Serialize, SchemaType, Debug)]
+pub struct LaunchPadsPage {
+    pub launch_pads: Vec<LaunchPadSummary>,
+    /// ID of the launch pad to start the next page from, if there are
+    /// more launch pads
+    pub next_id: Option<LaunchPadId>,
+}
+
+/// Defines the lightweight view of a launch-pad, without the holders
+/// and release cycles, to be listed in pages.
+#[derive(Serialize, SchemaType, Debug)]
+pub struct LaunchPadSummary {
+    pub id: LaunchPadId,
+    pub product: ProductView,
+    pub status: Status,
+    pub timeperiod: TimePeriod,
+    pub raised: Amount,
+    pub soft_cap: Amount,
+    pub hard_cap: Option<Amount>,
+    pub sold_tokens: TokenAmount,
+    pub available_tokens: TokenAmount,
+    pub payment_token: Option<PaymentToken>,
+}
+
+impl From<LaunchPadState<'_>> for LaunchPadSummary {
+    fn from(value: LaunchPadState<'_>) -> Self {
+        Self {
+            id: value.id,
+            product: value.product.clone().into(),
+            status: value.status.clone(),
+            timeperiod: value.timeperiod,
+            raised: value.collected,
+            soft_cap: value.soft_cap,
+            hard_cap: value.hard_cap,
+            sold_tokens: value.sold_tokens,
+            available_tokens: value.available_tokens,
+            payment_token: value.payment_token.clone(),
         }
     }
 }
@@ -91,6 +159,7 @@ pub struct ProductView {
     pub owner: AccountAddress,
     pub allocated_tokens: TokenAmount,
     pub base_price: Amount,
+    pub token_id: TokenIdVec,
 }
 
 impl From<Product> for ProductView {
@@ -100,6 +169,7 @@ impl From<Product> for ProductView {
             owner: value.owner,
             allocated_tokens: value.allocated_tokens,
             base_price: value.token_price,
+            token_id: value.token_id,
         }
     }
 }
@@ -112,8 +182,9 @@ impl From<Product> for ProductView {
 pub struct HolderView {
     pub tokens: TokenAmount,
     pub invested: Amount,
-    pub unlocked_release: Vec<(u8, UnlockedWrapper)>,
-    pub locked_release: Vec<(u8, LockedWrapper)>,
+    pub claimed_tokens: TokenAmount,
+    pub claimed_lp_tokens: TokenAmount,
+    pub refunded: bool,
 }
 
 impl From<StateRef<'_, HolderInfo>> for HolderView {
@@ -121,52 +192,68 @@ impl From<StateRef<'_, HolderInfo>> for HolderView {
         Self {
             tokens: value.tokens,
             invested: value.invested,
-            unlocked_release: value
-                .release_data
-                .unlocked
-                .iter()
-                .map(|details| (*details.0, (*details.1).into()))
-                .collect(),
-            locked_release: value
-                .release_data
-                .locked
-                .iter()
-                .map(|details| (*details.0, (*details.1).into()))
-                .collect(),
+            claimed_tokens: value.claimed_tokens,
+            claimed_lp_tokens: value.claimed_lp_tokens,
+            refunded: value.refunded,
         }
     }
 }
 
-/// Wrapper for unlocked release cycles to implement the schema
-/// for schema deserialization.
-#[derive(Serialize)]
-pub struct UnlockedWrapper(pub (TokenAmount, Timestamp, bool));
+/// Defines the position of a holder in a launch pad, summarizing the
+/// release cycles as of the current block time.
+#[derive(Serialize, SchemaType, Debug)]
+pub struct HolderPosition {
+    pub tokens: TokenAmount,
+    pub invested: Amount,
+    pub refunded: bool,
+    /// Amount of tokens of the elapsed cycles, yet to be claimed
+    pub claimable_tokens: TokenAmount,
+    /// Amount of LP tokens of the elapsed cycles, yet to be claimed
+    pub claimable_lp_tokens: TokenAmount,
+    /// Time of the earliest cycle, locked or unlocked, yet to elapse
+    pub next_unlock: Option<Timestamp>,
+    pub claimed_tokens: TokenAmount,
+    pub claimed_lp_tokens: TokenAmount,
+}
 
-impl SchemaType for UnlockedWrapper {
-    fn get_type() -> crate::schema::Type {
-        let fields = schema::Fields::Unnamed(vec![
-            TokenAmount::get_type(),
-            Timestamp::get_type(),
-            bool::get_type(),
-        ]);
+impl HolderPosition {
+    /// Computes the position of the holder from the release schedule of
+    /// the launch pad at the given time.
+    pub fn new(launch_pad: &LaunchPad, holder: &HolderInfo, now: Timestamp) -> Self {
+        let mut position = Self {
+            tokens: holder.tokens,
+            invested: holder.invested,
+            refunded: holder.refunded,
+            claimable_tokens: 0.into(),
+            claimable_lp_tokens: 0.into(),
+            next_unlock: None,
+            claimed_tokens: holder.claimed_tokens,
+            claimed_lp_tokens: holder.claimed_lp_tokens,
+        };
 
-        schema::Type::Struct(fields)
-    }
-}
+        if let Some(schedule) = &launch_pad.release_schedule {
+            let cycle = schedule.elapsed_cycles(schedule.cycles, now);
+            position.claimable_tokens = launch_pad.claimable_tokens(holder, cycle);
+            if cycle < schedule.cycles {
+                position.next_unlock = Some(schedule.cycle_time(cycle + 1));
+            }
 
-impl concordium_std::fmt::Debug for UnlockedWrapper {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "({} tokens, {} millis, {})",
-            self.0 .0 .0, self.0 .1.millis, self.0 .2
-        )
-    }
-}
+            if schedule.liquidity.is_some() {
+                let lp_cycle = schedule.elapsed_cycles(schedule.lp_cycles, now);
+                position.claimable_lp_tokens = launch_pad.claimable_lp_tokens(holder, lp_cycle);
+                if lp_cycle < schedule.lp_cycles {
+                    let next_lp_unlock = schedule.cycle_time(lp_cycle + 1);
+                    if position
+                        .next_unlock
+                        .map_or(true, |next| next_lp_unlock < next)
+                    {
+                        position.next_unlock = Some(next_lp_unlock);
+                    }
+                }
+            }
+        }
 
-impl From<(TokenAmount, Timestamp, bool)> for UnlockedWrapper {
-    fn from(value: (TokenAmount, Timestamp, bool)) -> Self {
-        Self(value)
+        position
     }
 }
 
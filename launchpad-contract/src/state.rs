@@ -1,26 +1,29 @@
-use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdU8 as TokenID};
+use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdVec};
 use concordium_std::{
-    AccountAddress, Amount, ContractAddress, DeserialWithState, Duration, HasChainMetadata,
-    HasCommonData, ReceiveContext, SchemaType, Serial, Serialize, StateApi, StateBuilder, StateMap,
-    StateMapIterMut, StateRef, StateRefMut, Timestamp,
+    bail, ensure, AccountAddress, Address, Amount, ContractAddress, DeserialWithState, Duration,
+    HasChainMetadata, HasCommonData, HasStateApi, MetadataUrl, SchemaType, Serial, Serialize,
+    StateApi, StateBuilder, StateMap, StateRef, StateRefMut, Timestamp,
 };
 
 use crate::{
     errors::Error,
-    params::{CreateParams, Months},
-    ProductName,
+    helper::{linear_release, pro_rata, pro_rata_tokens},
+    params::{CreateParams, LaunchPadKey, Months, RoundParams},
+    LaunchPadId, ProductName, CYCLE_DURATION,
 };
 
 /// Alias for mutable state reference of a `LaunchPad` type
-pub type LaunchPadStateMut<'a> = StateRefMut<'a, LaunchPad, StateApi>;
+pub type LaunchPadStateMut<'a, S = StateApi> = StateRefMut<'a, LaunchPad<S>, S>;
 /// Alias for immutable state reference of a `LaunchPad` type
-pub type LaunchPadState<'a> = StateRef<'a, LaunchPad>;
-/// Iterator over launch-pad's holders and their associated holder-info
-pub type HoldersMut<'a> = StateMapIterMut<'a, AccountAddress, HolderInfo, StateApi>;
+pub type LaunchPadState<'a, S = StateApi> = StateRef<'a, LaunchPad<S>>;
 
 /// Number of days in a month
 pub const DAYS: u64 = 31;
 
+/// Version of the contract state layout, to be bumped whenever the
+/// layout of `State`, `LaunchPad` or `HolderInfo` changes.
+pub const STATE_VERSION: u32 = 11;
+
 /// The state of the smart contract.
 /// This state can be viewed by querying the node with the command
 /// `concordium-client contract invoke` using the `view` function as entry
@@ -28,19 +31,39 @@ pub const DAYS: u64 = 31;
 #[derive(Serial, DeserialWithState, Debug)]
 #[concordium(state_parameter = "S")]
 pub struct State<S = StateApi> {
-    /// A mapping including all launchpad that have been added to this contract.
-    pub launchpads: StateMap<ProductName, LaunchPad, S>,
+    /// Version of the state layout. It is kept as the first field so that
+    /// a new module can read it before parsing the rest of the state, and
+    /// migrate from the older layouts accordingly.
+    pub version: u32,
+    /// A mapping including all launchpad that have been added to this contract,
+    /// by their IDs.
+    pub launchpads: StateMap<LaunchPadId, LaunchPad<S>, S>,
+    /// Index of the launchpad IDs by their product names
+    pub launchpad_ids: StateMap<ProductName, LaunchPadId, S>,
     /// Container which holds the list of all the investors on the platform with
     /// associative list representing the launchpads in which they contribute
-    pub investors: StateMap<AccountAddress, Vec<ProductName>, S>,
+    pub investors: StateMap<AccountAddress, Vec<LaunchPadId>, S>,
     /// Admin details of the contract
     pub admin: Admin,
+    /// Account proposed by the admin to take over the admin role,
+    /// until it accepts the proposal
+    pub pending_admin: Option<AccountAddress>,
+    /// Container which holds the list of accounts with their associative
+    /// list of roles granted for the privileged operations
+    pub roles: StateMap<AccountAddress, Vec<Role>, S>,
     /// A counter that is sequentially increased whenever a new launchpad is added to
-    /// the contract.
-    pub counter: u32,
+    /// the contract. It is also the ID to be assigned to the next launchpad.
+    pub counter: LaunchPadId,
+    /// Reason of the platform wide emergency pause, if the platform is
+    /// paused. All the operations on launch pads are frozen while paused.
+    pub pause_reason: Option<String>,
+    /// DEX contracts registered by the admin, besides the platform DEX,
+    /// among which the product owner can pick the one to lock the
+    /// liquidity of a launch pad.
+    pub dexes: Vec<ContractAddress>,
 }
 
-impl State {
+impl<S: HasStateApi> State<S> {
     /// Getter function to get the platform registeration fee
     /// for launch-pad creation
     ///
@@ -68,34 +91,97 @@ impl State {
         self.admin.dex_address
     }
 
-    /// Gets the mutable reference to `LaunchPad` by product name with
-    /// its associative ID
+    /// Checks if the DEX contract is either the platform DEX or one
+    /// of the DEX contracts registered by the admin
+    pub fn is_dex_registered(&self, dex: &ContractAddress) -> bool {
+        *dex == self.dex_address() || self.dexes.contains(dex)
+    }
+
+    /// Ensures that the given sender is the platform admin
+    ///
+    /// Returns `Error::UnAuthorized` if the sender is not the admin
+    pub fn ensure_admin(&self, sender: Address) -> Result<(), Error> {
+        ensure!(
+            sender.matches_account(&self.admin_address()),
+            Error::UnAuthorized
+        );
+        Ok(())
+    }
+
+    /// Ensures that the platform is not paused
+    ///
+    /// Returns `Error::Paused` if the platform is paused
+    pub fn ensure_not_paused(&self) -> Result<(), Error> {
+        ensure!(self.pause_reason.is_none(), Error::Paused);
+        Ok(())
+    }
+
+    /// Checks if the account holds the given role. Platform admin
+    /// implicitly holds every role.
+    pub fn has_role(&self, account: &AccountAddress, role: Role) -> bool {
+        if *account == self.admin_address() {
+            return true;
+        }
+
+        self.roles
+            .get(account)
+            .map_or(false, |roles| roles.contains(&role))
+    }
+
+    /// Ensures that the given sender is an account holding the given
+    /// role
+    ///
+    /// Returns `Error::UnAuthorized` if the sender does not hold the role
+    pub fn ensure_role(&self, sender: Address, role: Role) -> Result<(), Error> {
+        match sender {
+            Address::Account(account) if self.has_role(&account, role) => Ok(()),
+            _ => Err(Error::UnAuthorized),
+        }
+    }
+
+    /// Resolves the ID of the launch pad, given either its ID or its
+    /// product name
+    ///
+    /// Returns `Error::NotFound` if the LaunchPad does not exist.
+    pub fn launchpad_id(&self, key: &LaunchPadKey) -> Result<LaunchPadId, Error> {
+        let id = match key {
+            LaunchPadKey::Id(id) => *id,
+            LaunchPadKey::Name(name) => *self.launchpad_ids.get(name).ok_or(Error::NotFound)?,
+        };
+
+        Ok(id)
+    }
+
+    /// Gets the mutable reference to `LaunchPad` by either its ID or
+    /// its product name
     ///
     /// Returns `LaunchPadError` if the LaunchPad does not exist.
     pub fn get_mut_launchpad(
         &mut self,
-        product_name: String,
-    ) -> Result<LaunchPadStateMut<'_>, Error> {
-        if let Some(launchpad) = self.launchpads.get_mut(&product_name) {
+        key: LaunchPadKey,
+    ) -> Result<LaunchPadStateMut<'_, S>, Error> {
+        let id = self.launchpad_id(&key)?;
+        if let Some(launchpad) = self.launchpads.get_mut(&id) {
             return Ok(launchpad);
         }
 
         Err(Error::NotFound)
     }
 
-    /// Gets the immutable reference to `LaunchPad` by product name with
-    /// its associative ID
+    /// Gets the immutable reference to `LaunchPad` by either its ID or
+    /// its product name
     ///
     /// Returns `LaunchPadError` if the LaunchPad does not exist.
-    pub fn get_launchpad(&self, product_name: String) -> Result<LaunchPadState<'_>, Error> {
-        if let Some(launchpad) = self.launchpads.get(&product_name) {
+    pub fn get_launchpad(&self, key: LaunchPadKey) -> Result<LaunchPadState<'_, S>, Error> {
+        let id = self.launchpad_id(&key)?;
+        if let Some(launchpad) = self.launchpads.get(&id) {
             return Ok(launchpad);
         }
 
         Err(Error::NotFound)
     }
 
-    pub fn my_launch_pads(&self, holder: AccountAddress) -> Result<Vec<ProductName>, Error> {
+    pub fn my_launch_pads(&self, holder: AccountAddress) -> Result<Vec<LaunchPadId>, Error> {
         if let Some(ids) = self.investors.get(&holder) {
             return Ok(ids.clone());
         }
@@ -109,6 +195,8 @@ type LockedRelease = (TokenAmount, TokenIdU64, Timestamp, bool);
 #[derive(Serial, DeserialWithState, Debug)]
 #[concordium(state_parameter = "S")]
 pub struct LaunchPad<S = StateApi> {
+    /// Sequential ID assigned to the launch-pad at its creation
+    pub id: LaunchPadId,
     /// Product for which the presale is going to be established
     pub product: Product,
     /// Timeperiod of a launch-pad until it's expiry, in other words
@@ -153,26 +241,84 @@ pub struct LaunchPad<S = StateApi> {
     pub available_tokens: TokenAmount,
     /// Amount of tokens sold from the total allocated tokens.
     pub sold_tokens: TokenAmount,
+    /// Amount of product tokens currently held by the contract on
+    /// behalf of the launch pad.
+    pub token_balance: TokenAmount,
+    /// Registeration fee paid by the product owner at the time of
+    /// launch pad creation
+    pub fee_paid: Amount,
+    /// Optional ending time of the allowlist phase, which starts
+    /// along with the presale
+    pub allowlist_end: Option<Timestamp>,
+    /// List of allowlisted accounts with the amount of tokens left
+    /// from their allocation for the allowlist phase
+    pub allowlist: StateMap<AccountAddress, TokenAmount, S>,
+    /// Ordered list of sale rounds, along with their progress
+    pub rounds: Vec<Round>,
+    /// Optional CIS2 token accepted as payment for investments instead
+    /// of CCD
+    pub payment_token: Option<PaymentToken>,
+    /// Keeps track if the deposited product tokens are frozen for
+    /// review, after the launch pad is forcibly canceled by the admin
+    pub frozen: bool,
+    /// Release schedule shared by all the holders, set once the raised
+    /// funds are withdrawn by the product owner
+    pub release_schedule: Option<ReleaseSchedule>,
+    /// DEX contract in which the liquidity of the launch pad is locked,
+    /// and which holds the LPTokens of the launch pad
+    pub dex: ContractAddress,
+    /// Metadata URL of the product token, as reported by its CIS2
+    /// contract when the launch pad was created
+    pub token_metadata: MetadataUrl,
+    /// Keeps track if the start of the cliff period is already logged
+    pub cliff_started: bool,
 }
 
-impl LaunchPad {
+impl<S: HasStateApi> LaunchPad<S> {
     /// A constructor function to create a new `LaunchPad` instance
     /// from user parameters.
     ///
-    /// Returns a `LaunchPad` and a 64-bit ID associated with it.
+    /// Returns the product name along with the `LaunchPad` with the given ID.
     pub fn from_create_params(
         params: CreateParams,
-        state_builder: &mut StateBuilder,
+        id: LaunchPadId,
+        fee_paid: Amount,
+        dex: ContractAddress,
+        token_metadata: MetadataUrl,
+        state_builder: &mut StateBuilder<S>,
     ) -> (ProductName, Self) {
         let cliff = params
             .launchpad_end_time()
             .checked_add(Duration::from_days(params.lockup_details.cliff * DAYS))
             .unwrap();
+        // Launch pad without explicit rounds runs as a single round
+        let rounds = if params.rounds.is_empty() {
+            vec![Round::from(RoundParams {
+                timeperiod: params.timeperiod,
+                token_price: params.product.token_price,
+                allocated_tokens: params.product.allocated_tokens,
+                vest_limits: params.vest_limits.clone(),
+            })]
+        } else {
+            params.rounds.into_iter().map(Round::from).collect()
+        };
         (
             params.product.name.clone(),
             Self {
+                id,
                 available_tokens: params.product.allocated_tokens,
                 sold_tokens: 0.into(),
+                token_balance: 0.into(),
+                fee_paid,
+                allowlist_end: params.allowlist_end,
+                allowlist: state_builder.new_map(),
+                rounds,
+                payment_token: params.payment_token,
+                frozen: false,
+                release_schedule: None,
+                dex,
+                token_metadata,
+                cliff_started: false,
                 product: params.product,
                 timeperiod: params.timeperiod,
                 soft_cap: params.soft_cap,
@@ -214,9 +360,9 @@ impl LaunchPad {
     /// Getter method to get the CIS2 token ID of tokens listed for
     /// presale in current launch-pad.
     ///
-    /// Returns `TokenID`
-    pub fn get_product_token_id(&self) -> TokenID {
-        self.product.token_id
+    /// Returns `TokenIdVec`
+    pub fn get_product_token_id(&self) -> TokenIdVec {
+        self.product.token_id.clone()
     }
 
     /// Getter method to get the owner account address of the  
@@ -251,10 +397,36 @@ impl LaunchPad {
     /// Checks if the vesting is completed, by checking whether
     ///
     /// - Vesting duration has been elapsed
+    /// - Hard cap has been reached
     ///
     /// Returns `true` if any of the above statement is true
-    pub fn is_finished(&self, ctx: &ReceiveContext) -> bool {
-        self.timeperiod.end < ctx.metadata().block_time()
+    pub fn is_finished(&self, ctx: &impl HasCommonData) -> bool {
+        self.timeperiod.end < ctx.metadata().block_time() || self.reached_hard_cap()
+    }
+
+    /// Checks if the launch pad is in its allowlist phase, during which
+    /// only the allowlisted accounts are allowed to vest
+    pub fn is_allowlist_phase(&self, current: Timestamp) -> bool {
+        self.allowlist_end.map_or(false, |end| current < end)
+    }
+
+    /// Deducts the tokens from the allocation of an allowlisted holder.
+    ///
+    /// Returns `Error::UnAuthorized` if the holder is not allowlisted or
+    /// `Error::Limit` if the tokens exceeds the allocation left.
+    pub fn use_allocation(
+        &mut self,
+        holder: AccountAddress,
+        token_amount: TokenAmount,
+    ) -> Result<(), Error> {
+        match self.allowlist.get_mut(&holder) {
+            Some(mut allocation) => {
+                ensure!(token_amount <= *allocation, Error::Limit);
+                *allocation -= token_amount;
+                Ok(())
+            }
+            None => bail!(Error::UnAuthorized),
+        }
     }
 
     /// Checks if the pause duration has elapsed
@@ -272,12 +444,54 @@ impl LaunchPad {
         self.status == Status::COMPLETED
     }
 
+    /// Checks if the Launch pad is rejected
+    pub fn is_rejected(&self) -> bool {
+        self.status == Status::REJECTED
+    }
+
+    /// Checks if the holders are allowed to get their investment
+    /// back, which is only the case if
+    ///
+    /// - Launch pad is canceled
+    /// - Vesting is finished without reaching the soft cap
+    ///
+    /// Returns `true` if any of the above statement is true
+    pub fn is_refundable(&self, ctx: &impl HasCommonData) -> bool {
+        self.is_canceled() || (self.is_finished(ctx) && !self.reached_soft_cap())
+    }
+
     /// Returns the base price of allocated token for presale
     /// in CCD.
     pub fn product_base_price(&self) -> Amount {
         self.product.token_price
     }
 
+    /// Returns the price at which the product tokens are paired with the
+    /// raised funds in liquidity, which is the token price of the last
+    /// sale round, usually the public round.
+    pub fn liquidity_price(&self) -> Amount {
+        self.rounds
+            .last()
+            .map_or(self.product.token_price, |round| round.token_price)
+    }
+
+    /// Checks if the launch pad accepts the given CIS2 token as
+    /// payment for investments
+    pub fn accepts_payment_token(&self, contract: ContractAddress, token_id: &TokenIdVec) -> bool {
+        self.payment_token.as_ref().map_or(false, |token| {
+            token.contract == contract && token.token_id == *token_id
+        })
+    }
+
+    /// Gets the index of the sale round active at the given time,
+    /// if any.
+    pub fn active_round(&self, current: Timestamp) -> Option<u8> {
+        self.rounds
+            .iter()
+            .position(|round| round.is_active(current))
+            .map(|index| index as u8)
+    }
+
     /// Returns the product name as `String`, for which the
     /// launch pad is created for presale.
     ///
@@ -287,19 +501,72 @@ impl LaunchPad {
         self.product.name.clone()
     }
 
-    /// Returns the min token amount acceptable for vesting
-    pub fn vest_min(&self) -> TokenAmount {
-        self.vest_limits.min
+    /// Checks if the soft cap is reached
+    pub fn reached_soft_cap(&self) -> bool {
+        self.collected >= self.soft_cap
     }
 
-    /// Returns the max token amount acceptable for vesting
-    pub fn vest_max(&self) -> TokenAmount {
-        self.vest_limits.max
+    /// Checks if the hard cap is reached, launch pads without a
+    /// hard cap never reach it
+    ///
+    /// Hard cap is also reached once the room left under it is below the
+    /// cheapest minimum buy of the rounds, as nobody can invest anymore
+    pub fn reached_hard_cap(&self) -> bool {
+        self.hard_cap.map_or(false, |hard_cap| {
+            self.collected >= hard_cap || hard_cap - self.collected < self.min_buy()
+        })
     }
 
-    /// Checks if the soft cap is reached
-    pub fn reached_soft_cap(&self) -> bool {
-        self.collected >= self.soft_cap
+    /// Returns the least amount an investment can cost, which is the
+    /// minimum vesting limit at the token price of the cheapest round
+    fn min_buy(&self) -> Amount {
+        self.rounds
+            .iter()
+            .map(|round| {
+                round
+                    .price_of(round.vest_limits.min)
+                    .unwrap_or(Amount::from_micro_ccd(u64::MAX))
+            })
+            .min()
+            .unwrap_or(Amount::zero())
+    }
+
+    /// Ensures that the investment amount does not exceed the
+    /// hard cap of the launch pad
+    ///
+    /// Returns `Error::HardCap` if the hard cap is exceeded
+    pub fn ensure_within_hard_cap(&self, amount: Amount) -> Result<(), Error> {
+        if let Some(hard_cap) = self.hard_cap {
+            ensure!(self.collected + amount <= hard_cap, Error::HardCap);
+        }
+        Ok(())
+    }
+
+    /// Marks the cliff period as started, once the sale is finished
+    /// with the soft cap reached.
+    ///
+    /// Returns the time the cliff period started from, only the first
+    /// time it is called afterwards.
+    pub fn start_cliff(&mut self, ctx: &impl HasCommonData) -> Option<Timestamp> {
+        if self.cliff_started
+            || self.is_canceled()
+            || !self.is_finished(ctx)
+            || !self.reached_soft_cap()
+        {
+            return None;
+        }
+
+        self.cliff_started = true;
+        Some(ctx.metadata().block_time().min(self.timeperiod.end))
+    }
+
+    /// Ensures that the cliff period of the launch pad has elapsed,
+    /// after which the release cycles start
+    ///
+    /// Returns `Error::Cliff` if the launch pad is still in its cliff
+    pub fn ensure_cliff_elapsed(&self, current: Timestamp) -> Result<(), Error> {
+        ensure!(current >= self.lock_up.cliff, Error::Cliff);
+        Ok(())
     }
 
     /// Gets the immutable reference to holder information
@@ -317,40 +584,178 @@ impl LaunchPad {
         Err(Error::NotFound)
     }
 
-    /// Updates the unlocked release data related to a specific holder in the
-    /// launch pad.
-    pub fn set_holder_unlocked_release_info(
+    /// Gets the amount of product tokens released to the holder until the
+    /// given cycle, which are not yet claimed by the holder
+    pub fn claimable_tokens(&self, holder: &HolderInfo, cycle: Months) -> TokenAmount {
+        self.release_schedule.as_ref().map_or(0.into(), |schedule| {
+            schedule.claimable_tokens(holder, self.sold_tokens, cycle)
+        })
+    }
+
+    /// Gets the amount of LPTokens released to the holder until the given
+    /// locked cycle, which are not yet claimed by the holder
+    pub fn claimable_lp_tokens(&self, holder: &HolderInfo, cycle: Months) -> TokenAmount {
+        self.release_schedule.as_ref().map_or(0.into(), |schedule| {
+            schedule.claimable_lp_tokens(holder, self.collected, cycle)
+        })
+    }
+
+    /// Claims the product tokens released to the holder until the given
+    /// cycle, keeping track of the tokens distributed to all the holders.
+    /// Holder is settled once the final cycle is claimed.
+    ///
+    /// Returns the amount of tokens claimed, or `None` if there is nothing
+    /// left to claim until the given cycle, even if the holder is settled.
+    pub fn claim_tokens(
         &mut self,
         holder: AccountAddress,
-        cycle: u8,
-        claimed: bool,
-    ) {
-        self.holders
-            .get_mut(&holder)
-            .unwrap()
-            .release_data
-            .unlocked
-            .get_mut(&cycle)
-            .unwrap()
-            .2 = claimed;
-    }
-
-    /// Updates the locked release info related to a specific holder in the
-    /// launch pad.
-    pub fn set_holder_locked_release_info(
+        cycle: Months,
+    ) -> Result<Option<TokenAmount>, Error> {
+        let schedule = self.release_schedule.as_ref().ok_or(Error::InCorrect)?;
+        let info = self.get_holder_info(holder)?;
+        let amount = schedule.claimable_tokens(&info, self.sold_tokens, cycle);
+        let settles = cycle >= schedule.cycles && !info.tokens_settled;
+        let bought = info.tokens;
+        drop(info);
+
+        if amount == 0.into() && !settles {
+            return Ok(None);
+        }
+
+        let mut info = self.get_mut_holder_info(holder)?;
+        info.claimed_tokens += amount;
+        info.tokens_settled |= settles;
+        drop(info);
+
+        let schedule = self.release_schedule.as_mut().ok_or(Error::InCorrect)?;
+        schedule.distributed_tokens += amount;
+        if settles {
+            schedule.tokens_settled += bought;
+        }
+
+        Ok(Some(amount).filter(|amount| amount.0 > 0))
+    }
+
+    /// Claims the LPTokens released to the holder until the given locked
+    /// cycle, keeping track of the LPTokens distributed to all the holders.
+    /// Holder is settled once the final locked cycle is claimed.
+    ///
+    /// Returns the amount of LPTokens claimed, or `None` if there is nothing
+    /// left to claim until the given cycle, even if the holder is settled.
+    pub fn claim_lp_tokens(
         &mut self,
         holder: AccountAddress,
-        cycle: u8,
-        claimed: bool,
-    ) {
-        self.holders
-            .get_mut(&holder)
-            .unwrap()
-            .release_data
-            .locked
-            .get_mut(&cycle)
-            .unwrap()
-            .3 = claimed;
+        cycle: Months,
+    ) -> Result<Option<TokenAmount>, Error> {
+        let schedule = self.release_schedule.as_ref().ok_or(Error::InCorrect)?;
+        if schedule.liquidity.is_none() {
+            return Ok(None);
+        }
+        let info = self.get_holder_info(holder)?;
+        let amount = schedule.claimable_lp_tokens(&info, self.collected, cycle);
+        let settles = cycle >= schedule.lp_cycles && !info.lp_tokens_settled;
+        let invested = info.invested;
+        drop(info);
+
+        if amount == 0.into() && !settles {
+            return Ok(None);
+        }
+
+        let mut info = self.get_mut_holder_info(holder)?;
+        info.claimed_lp_tokens += amount;
+        info.lp_tokens_settled |= settles;
+        drop(info);
+
+        let schedule = self.release_schedule.as_mut().ok_or(Error::InCorrect)?;
+        schedule.distributed_lp_tokens += amount;
+        if settles {
+            schedule.lp_tokens_settled += invested;
+        }
+
+        Ok(Some(amount).filter(|amount| amount.0 > 0))
+    }
+
+    /// Gets the mutable reference to holder information
+    /// releated to the launch pad.
+    ///
+    /// Returns `Ok()` if the holder exist or else returns `LaunchPadError`
+    pub fn get_mut_holder_info(
+        &mut self,
+        holder: AccountAddress,
+    ) -> Result<StateRefMut<'_, HolderInfo, S>, Error> {
+        if let Some(info) = self.holders.get_mut(&holder) {
+            return Ok(info);
+        }
+
+        Err(Error::NotFound)
+    }
+
+    /// Cancels the launch pad.
+    ///
+    /// Returns the registeration fee to be refunded to the owner, if the
+    /// launch pad is still in review, since the fee is only settled once
+    /// the launch pad is approved or rejected.
+    pub fn cancel(&mut self) -> Amount {
+        let fee = if self.status == Status::INREVIEW {
+            self.fee_paid
+        } else {
+            Amount::zero()
+        };
+        self.status = Status::CANCELED;
+        fee
+    }
+
+    /// Marks the holder as refunded and releases the tokens bought by
+    /// the holder back to the available tokens of the launch pad and
+    /// its sale rounds.
+    ///
+    /// Returns the invested amount in CCD to be refunded, or `Error::Claimed`
+    /// if the holder is already refunded.
+    pub fn refund_holder(&mut self, holder: AccountAddress) -> Result<Amount, Error> {
+        let (invested, tokens, round_tokens) = match self.holders.get_mut(&holder) {
+            Some(mut holder_info) => {
+                ensure!(!holder_info.refunded, Error::Claimed);
+                holder_info.refunded = true;
+                (
+                    holder_info.invested,
+                    holder_info.tokens,
+                    holder_info.round_tokens.clone(),
+                )
+            }
+            None => bail!(Error::NotFound),
+        };
+
+        for (round, tokens) in self.rounds.iter_mut().zip(round_tokens) {
+            round.collected -= round.price_of(tokens)?;
+            round.sold_tokens -= tokens;
+        }
+
+        self.collected -= invested;
+        self.sold_tokens -= tokens;
+        self.available_tokens += tokens;
+
+        Ok(invested)
+    }
+
+    /// Gets the amount of product tokens that can be reclaimed by the
+    /// product owner, which is
+    ///
+    /// - All the tokens held, if the launch pad is rejected, canceled or
+    ///   finished without reaching the soft cap
+    /// - Unsold tokens, if the launch pad is finished after reaching the
+    ///   soft cap
+    ///
+    /// Returns `Error::JobFailed` if none of the above statement is true
+    pub fn reclaimable_tokens(&self, ctx: &impl HasCommonData) -> Result<TokenAmount, Error> {
+        ensure!(!self.frozen, Error::Frozen);
+
+        if self.is_rejected() || self.is_refundable(ctx) {
+            return Ok(self.token_balance);
+        }
+
+        ensure!(self.is_finished(ctx), Error::JobFailed);
+
+        Ok(self.available_tokens)
     }
 
     /// Sets the locked releasse info related to the product owner of the
@@ -358,11 +763,60 @@ impl LaunchPad {
     pub fn set_locked_release_info(&mut self, cycle: u8, claimed: bool) {
         self.locked_release.get_mut(&cycle).unwrap().3 = claimed;
     }
+}
 
-    /// Returns an mutable iteratoor over the list of holders contributing
-    /// to the Launch pad.
-    pub fn get_holders_mut(&mut self) -> HoldersMut<'_> {
-        self.holders.iter_mut()
+/// Defines a single sale round of a launch pad, such as seed, private
+/// or public round, along with its progress.
+#[derive(Serialize, SchemaType, Clone, Debug)]
+pub struct Round {
+    /// Time window of the round
+    pub timeperiod: TimePeriod,
+    /// Per token price for the round
+    pub token_price: Amount,
+    /// Amount of tokens allocated for the round
+    pub allocated_tokens: TokenAmount,
+    /// Defines the maximum and minimum investment amounts acceptable
+    /// for the round
+    pub vest_limits: VestingLimits,
+    /// Amount of tokens sold in the round
+    pub sold_tokens: TokenAmount,
+    /// Amount that have been collected in the round
+    pub collected: Amount,
+}
+
+impl From<RoundParams> for Round {
+    fn from(value: RoundParams) -> Self {
+        Self {
+            timeperiod: value.timeperiod,
+            token_price: value.token_price,
+            allocated_tokens: value.allocated_tokens,
+            vest_limits: value.vest_limits,
+            sold_tokens: 0.into(),
+            collected: Amount::zero(),
+        }
+    }
+}
+
+impl Round {
+    /// Checks if the round is active at the given time
+    pub fn is_active(&self, current: Timestamp) -> bool {
+        self.timeperiod.start <= current && current <= self.timeperiod.end
+    }
+
+    /// Calculates the price in CCD to buy the given amount of tokens
+    /// at the round token price.
+    ///
+    /// Returns `Error::Limit` if the price overflows
+    pub fn price_of(&self, token_amount: TokenAmount) -> Result<Amount, Error> {
+        match self.token_price.micro_ccd.checked_mul(token_amount.0) {
+            Some(micro_ccd) => Ok(Amount::from_micro_ccd(micro_ccd)),
+            None => bail!(Error::Limit),
+        }
+    }
+
+    /// Returns the amount of tokens left for sale in the round
+    pub fn available_tokens(&self) -> TokenAmount {
+        self.allocated_tokens - self.sold_tokens
     }
 }
 
@@ -390,11 +844,61 @@ pub struct Product {
     pub token_price: Amount,
     /// Address of the CIS2 contract
     pub cis2_contract: ContractAddress,
+    /// On chain token identifier in CIS2 contract, of any length
+    pub token_id: TokenIdVec,
+}
+
+/// Defines the CIS2 token, such as a stablecoin, accepted by a launch
+/// pad as payment for investments.
+///
+/// When a launch pad accepts a payment token, all of its amounts such as
+/// token price, soft cap, hard cap and investments are denominated in the
+/// smallest unit of the payment token instead of micro CCD.
+#[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
+pub struct PaymentToken {
+    /// Address of the CIS2 contract of the payment token
+    pub contract: ContractAddress,
     /// On chain token identifier in CIS2 contract
-    pub token_id: TokenID,
+    pub token_id: TokenIdVec,
+}
+
+impl PaymentToken {
+    /// Converts the amount of a launch pad paid in the payment token,
+    /// which counts the smallest units of the token, to the token amount
+    pub fn to_token_amount(amount: Amount) -> TokenAmount {
+        TokenAmount(amount.micro_ccd)
+    }
+
+    /// Converts the token amount received in the payment token to the
+    /// amount of a launch pad paid in the token
+    pub fn to_amount(token_amount: TokenAmount) -> Amount {
+        Amount::from_micro_ccd(token_amount.0)
+    }
+}
+
+/// Funds of a launch pad to be transferred, either in CCD or in the
+/// payment token accepted by the launch pad.
+pub enum Funds {
+    /// Amount in CCD
+    Ccd(Amount),
+    /// Amount in the smallest unit of the payment token
+    Token(PaymentToken, TokenAmount),
 }
 
+impl Funds {
+    /// Creates the funds of the given amount of a launch pad, in its
+    /// payment token if it accepts one, otherwise in CCD
+    pub fn new(payment_token: Option<PaymentToken>, amount: Amount) -> Self {
+        match payment_token {
+            None => Self::Ccd(amount),
+            Some(token) => Self::Token(token, PaymentToken::to_token_amount(amount)),
+        }
+    }
+}
+
+// Variant names such as `Status::LIVE` are part of the public schema
 #[derive(Serialize, SchemaType, Clone, Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Status {
     /// When launchpas is approved and published for investments
     LIVE,
@@ -415,6 +919,21 @@ pub enum Status {
     COMPLETED,
 }
 
+/// Roles which can be granted to accounts by the admin, each role
+/// allows a specific set of privileged operations
+#[derive(Serialize, SchemaType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Allowed to grant and revoke roles and to update the
+    /// platform DEX
+    Admin,
+    /// Allowed to approve or reject the launch pads in review
+    Analyst,
+    /// Allowed to update the platform fee and shares
+    Treasurer,
+    /// Allowed to pause the platform in case of emergency
+    Pauser,
+}
+
 #[derive(Serialize, SchemaType, Clone, Debug)]
 pub struct Admin {
     /// Admin account address to which all the fee
@@ -447,56 +966,180 @@ impl Admin {
 }
 
 /// This type holds the information about a single holder and its
-/// contributions in the launch pad, along with the details regarding
-/// each release cycle related to the holder.
-#[derive(Serial, DeserialWithState, Debug)]
-#[concordium(state_parameter = "S")]
-pub struct HolderInfo<S = StateApi> {
+/// contributions in the launch pad, along with the amounts claimed by
+/// the holder from the release cycles.
+#[derive(Serialize, Debug)]
+pub struct HolderInfo {
     /// Total amount of token bought by the holder
     pub tokens: TokenAmount,
     /// Total amount in CCD raised by the holder
     pub invested: Amount,
-    /// Release data regarding each cycle claimed
+    /// Amount of tokens claimed by the holder so far from the
+    /// unlocked release cycles
+    pub claimed_tokens: TokenAmount,
+    /// Amount of LPTokens claimed by the holder so far from the
+    /// locked release cycles
+    pub claimed_lp_tokens: TokenAmount,
+    /// Keeps track if the invested amount is refunded
+    /// to the holder
+    pub refunded: bool,
+    /// Amount of tokens bought by the holder in each sale round
+    pub round_tokens: Vec<TokenAmount>,
+    /// Keeps track if the final unlocked release cycle is claimed
+    /// by the holder
+    pub tokens_settled: bool,
+    /// Keeps track if the final locked release cycle is claimed
     /// by the holder
-    pub release_data: Release<S>,
+    pub lp_tokens_settled: bool,
 }
 
 impl HolderInfo {
-    pub fn insert_locked_cycle(
-        &mut self,
-        cycle: u8,
-        token_amount: TokenAmount,
-        token_id: TokenIdU64,
-        timestamp: Timestamp,
-    ) {
-        let _ = self
-            .release_data
-            .locked
-            .insert(cycle, (token_amount, token_id, timestamp, false));
+    /// Gets the amount of tokens bought by the holder in a sale round
+    pub fn tokens_in_round(&self, round: u8) -> TokenAmount {
+        self.round_tokens
+            .get(round as usize)
+            .copied()
+            .unwrap_or_else(|| 0.into())
     }
 
-    pub fn insert_unlocked_cycle(
-        &mut self,
-        cycle: u8,
-        token_amount: TokenAmount,
-        timestamp: Timestamp,
-    ) {
-        let _ = self
-            .release_data
-            .unlocked
-            .insert(cycle, (token_amount, timestamp, false));
+    /// Adds the amount of tokens bought by the holder in a sale round
+    pub fn add_round_tokens(&mut self, round: u8, token_amount: TokenAmount) {
+        let index = round as usize;
+        if self.round_tokens.len() <= index {
+            self.round_tokens.resize(index + 1, 0.into());
+        }
+        self.round_tokens[index] += token_amount;
     }
 }
 
-#[derive(Serial, DeserialWithState, Debug)]
-#[concordium(state_parameter = "S")]
-pub struct Release<S = StateApi> {
-    /// Unlocked release information regarding each cycle of
-    /// release.
-    pub unlocked: StateMap<u8, (TokenAmount, Timestamp, bool), S>,
-    /// Locked (LPTokens) release information regarding each cycle of
-    /// release.
-    pub locked: StateMap<u8, (TokenAmount, TokenIdU64, Timestamp, bool), S>,
+/// Release schedule shared by all the holders of a launch pad. Amounts
+/// released to each holder are computed from it on demand, according
+/// to the holder's contribution, rather than being stored per holder.
+///
+/// Product tokens are shared according to the tokens bought by the holder,
+/// since rounds may sell the tokens at different prices, while LPTokens are
+/// shared according to the funds invested by the holder.
+#[derive(Serialize, SchemaType, Clone, Debug)]
+pub struct ReleaseSchedule {
+    /// Time at which the release cycles start, i.e. the end of the cliff
+    pub start: Timestamp,
+    /// Amount of product tokens released to all the holders
+    pub tokens: TokenAmount,
+    /// Number of cycles in which the product tokens are released
+    pub cycles: Months,
+    /// LPTokens, along with their amount, released to all the holders
+    /// if the liquidity is added
+    pub liquidity: Option<(TokenIdU64, TokenAmount)>,
+    /// Number of cycles in which the LPTokens are released
+    pub lp_cycles: Months,
+    /// Amount of product tokens claimed by all the holders so far
+    pub distributed_tokens: TokenAmount,
+    /// Tokens bought by the holders who claimed the final release cycle
+    /// of the product tokens
+    pub tokens_settled: TokenAmount,
+    /// Amount of LPTokens claimed by all the holders so far
+    pub distributed_lp_tokens: TokenAmount,
+    /// Investments of the holders who claimed the final release cycle
+    /// of the LPTokens
+    pub lp_tokens_settled: Amount,
+}
+
+impl ReleaseSchedule {
+    /// Gets the time at which the given release cycle is elapsed
+    pub fn cycle_time(&self, cycle: Months) -> Timestamp {
+        Timestamp::from_timestamp_millis(self.start.millis + CYCLE_DURATION * cycle)
+    }
+
+    /// Gets the number of release cycles, out of the given total cycles,
+    /// elapsed at the given time
+    pub fn elapsed_cycles(&self, total_cycles: Months, current: Timestamp) -> Months {
+        (current.millis.saturating_sub(self.start.millis) / CYCLE_DURATION).min(total_cycles)
+    }
+
+    /// Gets the amount of product tokens released to a holder, with the
+    /// given tokens bought out of the tokens sold, until the given cycle
+    pub fn released_tokens(
+        &self,
+        bought: TokenAmount,
+        sold: TokenAmount,
+        cycle: Months,
+    ) -> TokenAmount {
+        linear_release(
+            pro_rata_tokens(self.tokens, bought, sold),
+            self.cycles,
+            cycle,
+        )
+    }
+
+    /// Gets the amount of LPTokens released to a holder, with the given
+    /// investment out of the raised funds, until the given cycle
+    pub fn released_lp_tokens(
+        &self,
+        invested: Amount,
+        raised: Amount,
+        cycle: Months,
+    ) -> TokenAmount {
+        self.liquidity.map_or(0.into(), |(_, lp_tokens)| {
+            linear_release(pro_rata(lp_tokens, invested, raised), self.lp_cycles, cycle)
+        })
+    }
+
+    /// Gets the amount of product tokens released to the holder until the
+    /// given cycle, which are not yet claimed by the holder.
+    ///
+    /// Shares of the holders are rounded down, hence the last holder to
+    /// claim the final cycle gets every token left, so that the tokens
+    /// distributed to all the holders are exactly the released tokens.
+    pub fn claimable_tokens(
+        &self,
+        holder: &HolderInfo,
+        sold: TokenAmount,
+        cycle: Months,
+    ) -> TokenAmount {
+        if cycle >= self.cycles
+            && !holder.tokens_settled
+            && self.tokens_settled + holder.tokens >= sold
+        {
+            return self
+                .tokens
+                .0
+                .saturating_sub(self.distributed_tokens.0)
+                .into();
+        }
+
+        let released = self.released_tokens(holder.tokens, sold, cycle);
+        released.0.saturating_sub(holder.claimed_tokens.0).into()
+    }
+
+    /// Gets the amount of LPTokens released to the holder until the given
+    /// locked cycle, which are not yet claimed by the holder.
+    ///
+    /// Same as the product tokens, the last holder to claim the final
+    /// cycle gets every LPToken left.
+    pub fn claimable_lp_tokens(
+        &self,
+        holder: &HolderInfo,
+        raised: Amount,
+        cycle: Months,
+    ) -> TokenAmount {
+        let lp_tokens = match self.liquidity {
+            Some((_, lp_tokens)) => lp_tokens,
+            None => return 0.into(),
+        };
+
+        if cycle >= self.lp_cycles
+            && !holder.lp_tokens_settled
+            && self.lp_tokens_settled + holder.invested >= raised
+        {
+            return lp_tokens
+                .0
+                .saturating_sub(self.distributed_lp_tokens.0)
+                .into();
+        }
+
+        let released = self.released_lp_tokens(holder.invested, raised, cycle);
+        released.0.saturating_sub(holder.claimed_lp_tokens.0).into()
+    }
 }
 
 /// Holds the Lock-up details for launch-pad such as:
@@ -0,0 +1,231 @@
+use concordium_std::{
+    AccountAddress, Amount, ContractAddress, ModuleReference, OwnedEntrypointName, OwnedParameter,
+};
+
+use crate::{errors::Error, params::UpgradeParams, state::STATE_VERSION};
+
+use super::{
+    initialize_chain_and_contracts, update_contract, view_state, ADMIN, HOLDERS, OWNER,
+    PLATFORM_REG_FEE,
+};
+
+/// Verifies that only the admin can update the admin details and the shares
+/// can never exceed 100%.
+#[test]
+fn update_admin_details() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, _, dex_contract) = initialize_chain_and_contracts();
+
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            Amount::from_ccd(20),
+            None,
+            "LaunchPad.UpdateRegisterationFee",
+        ),
+        Err(Error::UnAuthorized)
+    );
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            ADMIN,
+            101u64,
+            None,
+            "LaunchPad.UpdateAllocationShare",
+        ),
+        Err(Error::InCorrect)
+    );
+
+    let new_dex = ContractAddress::new(dex_contract.index + 10, 0);
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        Amount::from_ccd(20),
+        None,
+        "LaunchPad.UpdateRegisterationFee",
+    )?;
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        5u64,
+        None,
+        "LaunchPad.UpdateAllocationShare",
+    )?;
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        10u64,
+        None,
+        "LaunchPad.UpdateLiquidityShare",
+    )?;
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        new_dex,
+        None,
+        "LaunchPad.UpdateDexAddress",
+    )?;
+
+    let admin = view_state(&mut chain, ADMIN, lp_contract).admin_info;
+
+    assert_ne!(admin.registeration_fee, PLATFORM_REG_FEE);
+    assert_eq!(admin.registeration_fee, Amount::from_ccd(20));
+    assert_eq!(admin.allocation_share, 5);
+    assert_eq!(admin.liquidity_share, 10);
+    assert_eq!(admin.dex_address, new_dex);
+
+    Ok(())
+}
+
+/// Verifies that the admin role is only handed over once the proposed
+/// account accepts it.
+#[test]
+fn hand_over_admin() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, _, _) = initialize_chain_and_contracts();
+
+    let new_admin: AccountAddress = HOLDERS[0];
+
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            new_admin,
+            (),
+            None,
+            "LaunchPad.AcceptAdmin",
+        ),
+        Err(Error::NotFound)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        new_admin,
+        None,
+        "LaunchPad.ProposeAdmin",
+    )?;
+
+    // Admin remains the same until the proposal is accepted
+    let state = view_state(&mut chain, ADMIN, lp_contract);
+    assert_eq!(state.admin_info.address, ADMIN);
+    assert_eq!(state.pending_admin, Some(new_admin));
+
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            HOLDERS[1],
+            (),
+            None,
+            "LaunchPad.AcceptAdmin",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        new_admin,
+        (),
+        None,
+        "LaunchPad.AcceptAdmin",
+    )?;
+
+    let state = view_state(&mut chain, ADMIN, lp_contract);
+    assert_eq!(state.admin_info.address, new_admin);
+    assert_eq!(state.pending_admin, None);
+
+    // Previous admin is not allowed anymore to update the admin details
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            ADMIN,
+            Amount::from_ccd(20),
+            None,
+            "LaunchPad.UpdateRegisterationFee",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    Ok(())
+}
+
+/// Verifies that only the admin can upgrade the contract module, optionally
+/// invoking the migration on the upgraded contract, while keeping the state.
+#[test]
+fn upgrade_contract() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, _, _) = initialize_chain_and_contracts();
+
+    let module = chain
+        .get_contract(lp_contract)
+        .expect("[Error] Launch pad contract not found")
+        .module_reference;
+
+    let upgrade_params = |module: ModuleReference, migrate: Option<&str>| UpgradeParams {
+        module,
+        migrate: migrate.map(|entrypoint| {
+            (
+                OwnedEntrypointName::new_unchecked(entrypoint.to_string()),
+                OwnedParameter::empty(),
+            )
+        }),
+    };
+
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            upgrade_params(module, None),
+            None,
+            "LaunchPad.upgrade",
+        ),
+        Err(Error::UnAuthorized)
+    );
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            ADMIN,
+            upgrade_params(ModuleReference::from([0u8; 32]), None),
+            None,
+            "LaunchPad.upgrade",
+        ),
+        Err(Error::FailedUpgradeMissingModule)
+    );
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            ADMIN,
+            upgrade_params(module, Some("missingMigration")),
+            None,
+            "LaunchPad.upgrade",
+        ),
+        Err(Error::MissingEntrypoint)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        upgrade_params(module, Some("viewState")),
+        None,
+        "LaunchPad.upgrade",
+    )?;
+
+    let state = view_state(&mut chain, ADMIN, lp_contract);
+    assert_eq!(state.version, STATE_VERSION);
+    assert_eq!(state.admin_info.address, ADMIN);
+
+    Ok(())
+}
@@ -0,0 +1,639 @@
+use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdVec};
+use concordium_smart_contract_testing::Chain;
+use concordium_std::{
+    test_infrastructure::TestStateBuilder, AccountAddress, Amount, ContractAddress, Duration,
+    MetadataUrl, Timestamp,
+};
+
+use crate::{
+    errors::Error,
+    params::{
+        ClaimAllParams, ClaimLockedParams, ClaimUnLockedParams, Claimer, RoundParams, VestParams,
+    },
+    state::{HolderInfo, LaunchPad, PaymentToken, ReleaseSchedule, TimePeriod, VestingLimits},
+    CYCLE_DURATION,
+};
+
+use super::{
+    claim_locked_tokens, claim_tokens, default_create_params, get_lp_token_balance,
+    get_token_balance, initialize_chain_and_contracts, invest, invest_with_token, mint_token,
+    setup_live_launch_pad, tick_until, update_contract, view_launch_pad, withdraw_raised_funds,
+    HOLDERS, OWNER, OWNER_TOKEN_ID, OWNER_TOKEN_URL, PAYMENT_TOKEN_ID, PLATFORM_REG_FEE,
+    PRODUCT_NAME,
+};
+
+/// Name of the second launch pad of the same product token
+const OTHER_PRODUCT_NAME: &str = "Pixpel Games";
+
+/// A helper function to invoke `ClaimAll` in launch pad to claim every
+/// elapsed release cycle of the given launch pads.
+fn claim_all(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    product_names: &[&str],
+    contract: ContractAddress,
+) -> Result<(), Error> {
+    update_contract(
+        chain,
+        contract,
+        invoker,
+        ClaimAllParams {
+            launch_pads: product_names.iter().map(|name| (*name).into()).collect(),
+        },
+        None,
+        "LaunchPad.ClaimAll",
+    )
+}
+
+/// A helper function which computes the amounts of tokens and LPTokens
+/// released to the holder of a launch pad until the given cycle.
+fn released_amounts(
+    chain: &mut Chain,
+    product_name: &str,
+    holder: AccountAddress,
+    cycle: u64,
+    contract: ContractAddress,
+) -> (u64, u64) {
+    let view = view_launch_pad(chain, OWNER, product_name.to_string(), contract);
+    let schedule = view.release_schedule.expect("Release schedule is set");
+    let info = &view
+        .holders
+        .iter()
+        .find(|(account, _)| *account == holder)
+        .expect("Holder exists")
+        .1;
+
+    (
+        schedule
+            .released_tokens(info.tokens, view.sold_tokens, cycle)
+            .0,
+        schedule
+            .released_lp_tokens(info.invested, view.raised, cycle)
+            .0,
+    )
+}
+
+/// Verifies that every elapsed release cycle of the holder, unlocked and
+/// locked, across several launch pads is claimed in one go once the cliff
+/// is over.
+#[test]
+fn claim_all_matured_cycles() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, dex_contract) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    let mut params = default_create_params(cis2_contract);
+    params.product.name = OTHER_PRODUCT_NAME.to_string();
+    params.soft_cap = Amount::from_ccd(5 * 3500);
+    setup_live_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    for (holder, product_name, token_amount) in [
+        (HOLDERS[0], PRODUCT_NAME, 2500),
+        (HOLDERS[1], PRODUCT_NAME, 2500),
+        (HOLDERS[0], OTHER_PRODUCT_NAME, 1000),
+        (HOLDERS[2], OTHER_PRODUCT_NAME, 2500),
+    ] {
+        invest(
+            &mut chain,
+            holder,
+            VestParams {
+                launch_pad: product_name.into(),
+                token_amount: token_amount.into(),
+            },
+            Amount::from_ccd(5 * token_amount),
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    for product_name in [PRODUCT_NAME, OTHER_PRODUCT_NAME] {
+        withdraw_raised_funds(&mut chain, OWNER, product_name.to_string(), lp_contract)?;
+    }
+
+    let product_names = [PRODUCT_NAME, OTHER_PRODUCT_NAME];
+
+    // Neither the holders nor the owner can claim during the cliff period,
+    // launch pads in their cliff are skipped and nothing is left to claim
+    assert_eq!(
+        claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract),
+        Err(Error::NotElapsed)
+    );
+    assert_eq!(
+        claim_locked_tokens(
+            &mut chain,
+            OWNER,
+            ClaimLockedParams {
+                claimer: Claimer::OWNER(1),
+                launch_pad: PRODUCT_NAME.into(),
+            },
+            lp_contract,
+        ),
+        Err(Error::Cliff)
+    );
+    assert_eq!(
+        claim_tokens(
+            &mut chain,
+            HOLDERS[0],
+            ClaimUnLockedParams {
+                cycle: 1,
+                launch_pad: PRODUCT_NAME.into(),
+            },
+            lp_contract,
+        ),
+        Err(Error::Cliff)
+    );
+    assert_eq!(
+        claim_locked_tokens(
+            &mut chain,
+            HOLDERS[0],
+            ClaimLockedParams {
+                claimer: Claimer::HOLDER(1),
+                launch_pad: PRODUCT_NAME.into(),
+            },
+            lp_contract,
+        ),
+        Err(Error::Cliff)
+    );
+
+    let cliff = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)
+        .lock_up
+        .cliff;
+    tick_until(&mut chain, cliff);
+
+    // Release cycles start after the cliff
+    assert_eq!(
+        claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract),
+        Err(Error::NotElapsed)
+    );
+
+    // Holder has not invested in one of the launch pads
+    assert_eq!(
+        claim_all(&mut chain, HOLDERS[1], &product_names, lp_contract),
+        Err(Error::NotFound)
+    );
+
+    // First two release cycles are elapsed
+    tick_until(
+        &mut chain,
+        Timestamp::from_timestamp_millis(cliff.millis + 2 * CYCLE_DURATION),
+    );
+
+    claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract)?;
+
+    let balances = |chain: &mut Chain| {
+        (
+            get_token_balance(
+                chain,
+                OWNER,
+                &[(HOLDERS[0].into(), OWNER_TOKEN_ID)],
+                cis2_contract,
+            )
+            .0[0],
+            get_lp_token_balance(
+                chain,
+                OWNER,
+                &[(HOLDERS[0].into(), TokenIdU64(1))],
+                dex_contract,
+            )
+            .0[0],
+        )
+    };
+
+    // Locked cycles of the holders are released along with the unlocked
+    // cycles, both launch pads share the same LP token
+    let expected = |chain: &mut Chain, cycle: u64| {
+        let (mut tokens, mut lp_tokens) = (0, 0);
+        for product_name in [PRODUCT_NAME, OTHER_PRODUCT_NAME] {
+            let (released, released_lp) =
+                released_amounts(chain, product_name, HOLDERS[0], cycle, lp_contract);
+            tokens += released;
+            lp_tokens += released_lp;
+        }
+        (TokenAmount(tokens), TokenAmount(lp_tokens))
+    };
+    assert_eq!(balances(&mut chain), expected(&mut chain, 2));
+
+    // Nothing more to claim until the next cycle
+    assert_eq!(
+        claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract),
+        Err(Error::NotElapsed)
+    );
+
+    tick_until(
+        &mut chain,
+        Timestamp::from_timestamp_millis(cliff.millis + 3 * CYCLE_DURATION),
+    );
+
+    claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract)?;
+
+    assert_eq!(balances(&mut chain), expected(&mut chain, 3));
+
+    Ok(())
+}
+
+/// Verifies that a launch pad still in its cliff is skipped by `ClaimAll`,
+/// while the released cycles of the matured launch pads in the same batch
+/// are claimed.
+#[test]
+fn claim_all_skips_launch_pads_in_cliff() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    // Second launch pad has a cliff twice as long as the first one
+    let mut params = default_create_params(cis2_contract);
+    params.product.name = OTHER_PRODUCT_NAME.to_string();
+    params.soft_cap = Amount::from_ccd(5 * 3500);
+    params.lockup_details.cliff = 6;
+    setup_live_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    for (holder, product_name, token_amount) in [
+        (HOLDERS[0], PRODUCT_NAME, 2500),
+        (HOLDERS[1], PRODUCT_NAME, 2500),
+        (HOLDERS[0], OTHER_PRODUCT_NAME, 1000),
+        (HOLDERS[2], OTHER_PRODUCT_NAME, 2500),
+    ] {
+        invest(
+            &mut chain,
+            holder,
+            VestParams {
+                launch_pad: product_name.into(),
+                token_amount: token_amount.into(),
+            },
+            Amount::from_ccd(5 * token_amount),
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    for product_name in [PRODUCT_NAME, OTHER_PRODUCT_NAME] {
+        withdraw_raised_funds(&mut chain, OWNER, product_name.to_string(), lp_contract)?;
+    }
+
+    let product_names = [PRODUCT_NAME, OTHER_PRODUCT_NAME];
+    let cliff = |chain: &mut Chain, product_name: &str| {
+        view_launch_pad(chain, OWNER, product_name.to_string(), lp_contract)
+            .lock_up
+            .cliff
+    };
+    let balance = |chain: &mut Chain| {
+        get_token_balance(
+            chain,
+            OWNER,
+            &[(HOLDERS[0].into(), OWNER_TOKEN_ID)],
+            cis2_contract,
+        )
+        .0[0]
+    };
+
+    // First release cycle of the first launch pad is elapsed, while the
+    // second launch pad is still in its cliff
+    let first_cliff = cliff(&mut chain, PRODUCT_NAME);
+    let other_cliff = cliff(&mut chain, OTHER_PRODUCT_NAME);
+    tick_until(
+        &mut chain,
+        Timestamp::from_timestamp_millis(first_cliff.millis + CYCLE_DURATION),
+    );
+    assert!(chain.block_time() < other_cliff);
+
+    claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract)?;
+
+    let (released, _) = released_amounts(&mut chain, PRODUCT_NAME, HOLDERS[0], 1, lp_contract);
+    assert_eq!(balance(&mut chain), TokenAmount(released));
+
+    // Only the launch pad in its cliff is left, nothing to claim
+    assert_eq!(
+        claim_all(&mut chain, HOLDERS[0], &[OTHER_PRODUCT_NAME], lp_contract),
+        Err(Error::NotElapsed)
+    );
+
+    // The second launch pad is claimed along once its first cycle elapses
+    tick_until(
+        &mut chain,
+        Timestamp::from_timestamp_millis(other_cliff.millis + CYCLE_DURATION),
+    );
+
+    claim_all(&mut chain, HOLDERS[0], &product_names, lp_contract)?;
+
+    let (other_released, _) =
+        released_amounts(&mut chain, OTHER_PRODUCT_NAME, HOLDERS[0], 1, lp_contract);
+    let (released, _) = released_amounts(&mut chain, PRODUCT_NAME, HOLDERS[0], 3, lp_contract);
+    assert_eq!(balance(&mut chain), TokenAmount(released + other_released));
+
+    Ok(())
+}
+
+/// Verifies that the shares which do not divide evenly among the holders are
+/// not lost by rounding down, as the last holder to claim the final cycle gets
+/// the remainder, and the holders receive exactly the released amounts.
+#[test]
+fn claim_all_exact_totals() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, dex_contract) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    // Three equal investments, of which the shares are not whole
+    for holder in HOLDERS.iter() {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2001.into(),
+            },
+            Amount::from_ccd(5 * 2001),
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    let schedule = view.release_schedule.expect("Release schedule is set");
+    let (lp_token_id, lp_tokens) = schedule.liquidity.expect("Liquidity is added");
+    assert_ne!(schedule.tokens.0 % 3, 0);
+
+    tick_until(
+        &mut chain,
+        Timestamp::from_timestamp_millis(view.lock_up.cliff.millis + 3 * CYCLE_DURATION),
+    );
+
+    for holder in HOLDERS.iter() {
+        claim_all(&mut chain, *holder, &[PRODUCT_NAME], lp_contract)?;
+    }
+
+    let tokens: u64 = get_token_balance(
+        &mut chain,
+        OWNER,
+        &HOLDERS
+            .iter()
+            .map(|holder| ((*holder).into(), OWNER_TOKEN_ID))
+            .collect::<Vec<_>>(),
+        cis2_contract,
+    )
+    .0
+    .iter()
+    .map(|amount| amount.0)
+    .sum();
+    let claimed_lp_tokens: u64 = get_lp_token_balance(
+        &mut chain,
+        OWNER,
+        &HOLDERS
+            .iter()
+            .map(|holder| ((*holder).into(), lp_token_id))
+            .collect::<Vec<_>>(),
+        dex_contract,
+    )
+    .0
+    .iter()
+    .map(|amount| amount.0)
+    .sum();
+
+    assert_eq!(TokenAmount(tokens), schedule.tokens);
+    assert_eq!(TokenAmount(claimed_lp_tokens), lp_tokens);
+
+    // Nothing is left to claim afterwards
+    assert_eq!(
+        claim_all(&mut chain, HOLDERS[0], &[PRODUCT_NAME], lp_contract),
+        Err(Error::NotElapsed)
+    );
+
+    Ok(())
+}
+
+/// Verifies that the product tokens are released to the holders according to
+/// the tokens they bought, rather than the funds they invested, when the
+/// rounds sell the tokens at different prices. Sale is paid in a CIS2 token
+/// so that no tokens are locked in liquidity.
+#[test]
+fn claim_bought_tokens_across_rounds() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    let round = |start: u64, end: u64, token_price: u64| RoundParams {
+        timeperiod: TimePeriod {
+            start: Timestamp::from_timestamp_millis(start),
+            end: Timestamp::from_timestamp_millis(end),
+        },
+        token_price: Amount::from_micro_ccd(token_price),
+        allocated_tokens: TokenAmount(2500),
+        vest_limits: VestingLimits {
+            min: TokenAmount(1000),
+            max: TokenAmount(2500),
+        },
+    };
+
+    let mut params = default_create_params(cis2_contract);
+    params.product.token_price = Amount::from_micro_ccd(2);
+    params.soft_cap = Amount::from_micro_ccd(10000);
+    params.hard_cap = None;
+    params.liquidity_details.liquidity_allocation = 0;
+    params.payment_token = Some(PaymentToken {
+        contract: cis2_contract,
+        token_id: TokenIdVec(vec![PAYMENT_TOKEN_ID.0]),
+    });
+    params.rounds = vec![round(0, 1000, 2), round(1500, 3000, 4)];
+
+    setup_live_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    // First holder buys 2500 tokens at 2, and the others buy 1500 and 1000
+    // tokens at 4 in the public round
+    for (holder, token_amount, price, time) in [
+        (HOLDERS[0], 2500u64, 2, 0),
+        (HOLDERS[1], 1500, 4, 1500),
+        (HOLDERS[2], 1000, 4, 1500),
+    ] {
+        tick_until(&mut chain, Timestamp::from_timestamp_millis(time));
+        mint_token(
+            &mut chain,
+            holder,
+            cis2_contract,
+            PAYMENT_TOKEN_ID,
+            OWNER_TOKEN_URL.to_string(),
+        );
+        invest_with_token(
+            &mut chain,
+            holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: token_amount.into(),
+            },
+            (PAYMENT_TOKEN_ID, TokenAmount(price * token_amount)),
+            cis2_contract,
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(2000));
+
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    let schedule = view.release_schedule.expect("Release schedule is set");
+    assert_eq!(schedule.tokens, TokenAmount(5000));
+
+    tick_until(&mut chain, schedule.cycle_time(schedule.cycles));
+
+    for holder in HOLDERS.iter() {
+        claim_all(&mut chain, *holder, &[PRODUCT_NAME], lp_contract)?;
+    }
+
+    let balances = get_token_balance(
+        &mut chain,
+        OWNER,
+        &HOLDERS
+            .iter()
+            .map(|holder| ((*holder).into(), OWNER_TOKEN_ID))
+            .collect::<Vec<_>>(),
+        cis2_contract,
+    );
+    assert_eq!(
+        balances.0,
+        vec![TokenAmount(2500), TokenAmount(1500), TokenAmount(1000)]
+    );
+
+    Ok(())
+}
+
+/// Verifies that the shares of the holders are computed without losing the
+/// contributions below one percent, and the final cycle releases the remainder
+/// of each share.
+#[test]
+fn release_schedule_precise_shares() {
+    let schedule = ReleaseSchedule {
+        start: Timestamp::from_timestamp_millis(0),
+        tokens: TokenAmount(10000),
+        cycles: 3,
+        liquidity: Some((TokenIdU64(1), TokenAmount(1000))),
+        lp_cycles: 3,
+        distributed_tokens: 0.into(),
+        tokens_settled: 0.into(),
+        distributed_lp_tokens: 0.into(),
+        lp_tokens_settled: Amount::zero(),
+    };
+    let (sold, raised) = (TokenAmount(1000), Amount::from_ccd(1000));
+
+    // Contribution below one percent of the sale
+    assert_eq!(
+        schedule.released_tokens(TokenAmount(5), sold, 3),
+        TokenAmount(50)
+    );
+    assert_eq!(
+        schedule.released_lp_tokens(Amount::from_ccd(5), raised, 3),
+        TokenAmount(5)
+    );
+
+    // Share of 3950 tokens is released as 1316 in each cycle, and the
+    // remainder in the final cycle
+    assert_eq!(
+        (1..=3)
+            .map(|cycle| schedule.released_tokens(TokenAmount(395), sold, cycle))
+            .collect::<Vec<_>>(),
+        vec![TokenAmount(1316), TokenAmount(2632), TokenAmount(3950)]
+    );
+
+    let (tokens, lp_tokens) =
+        [5, 395, 600]
+            .iter()
+            .fold((0, 0), |(tokens, lp_tokens), contribution| {
+                (
+                    tokens
+                        + schedule
+                            .released_tokens(TokenAmount(*contribution), sold, 3)
+                            .0,
+                    lp_tokens
+                        + schedule
+                            .released_lp_tokens(Amount::from_ccd(*contribution), raised, 3)
+                            .0,
+                )
+            });
+    assert_eq!((tokens, lp_tokens), (10000, 1000));
+}
+
+/// Verifies that a holder whose share rounds down to nothing is settled on
+/// the final cycle without claiming a zero amount, so that the last holder
+/// still gets the remainder.
+#[test]
+fn settle_holder_without_claimable_amount() {
+    let mut state_builder = TestStateBuilder::new();
+    let (_, mut launch_pad) = LaunchPad::from_create_params(
+        default_create_params(ContractAddress::new(1, 0)),
+        0,
+        PLATFORM_REG_FEE,
+        ContractAddress::new(2, 0),
+        MetadataUrl {
+            url: String::new(),
+            hash: None,
+        },
+        &mut state_builder,
+    );
+    launch_pad.sold_tokens = 1000.into();
+    launch_pad.collected = Amount::from_ccd(1000);
+    launch_pad.release_schedule = Some(ReleaseSchedule {
+        start: Timestamp::from_timestamp_millis(0),
+        tokens: TokenAmount(10),
+        cycles: 3,
+        liquidity: Some((TokenIdU64(1), TokenAmount(10))),
+        lp_cycles: 3,
+        distributed_tokens: 0.into(),
+        tokens_settled: 0.into(),
+        distributed_lp_tokens: 0.into(),
+        lp_tokens_settled: Amount::zero(),
+    });
+    for (holder, tokens) in [(HOLDERS[0], 5), (HOLDERS[1], 995)] {
+        let _ = launch_pad.holders.insert(
+            holder,
+            HolderInfo {
+                tokens: tokens.into(),
+                invested: Amount::from_ccd(tokens),
+                claimed_tokens: 0.into(),
+                claimed_lp_tokens: 0.into(),
+                refunded: false,
+                round_tokens: vec![tokens.into()],
+                tokens_settled: false,
+                lp_tokens_settled: false,
+            },
+        );
+    }
+
+    // Share of 5 sold tokens in 10 released tokens rounds down to zero
+    assert_eq!(launch_pad.claim_tokens(HOLDERS[0], 3), Ok(None));
+    assert_eq!(launch_pad.claim_lp_tokens(HOLDERS[0], 3), Ok(None));
+
+    let info = launch_pad.get_holder_info(HOLDERS[0]).unwrap();
+    assert!(info.tokens_settled && info.lp_tokens_settled);
+    assert_eq!(
+        (info.claimed_tokens, info.claimed_lp_tokens),
+        (0.into(), 0.into())
+    );
+    drop(info);
+
+    // Nothing is claimed again once settled
+    assert_eq!(launch_pad.claim_tokens(HOLDERS[0], 3), Ok(None));
+
+    assert_eq!(
+        launch_pad.claim_tokens(HOLDERS[1], 3),
+        Ok(Some(TokenAmount(10)))
+    );
+    assert_eq!(
+        launch_pad.claim_lp_tokens(HOLDERS[1], 3),
+        Ok(Some(TokenAmount(10)))
+    );
+}
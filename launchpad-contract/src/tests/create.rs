@@ -0,0 +1,114 @@
+use concordium_cis2::{
+    BalanceOfQueryResponse, SupportResult, SupportsQueryResponse, TokenAmountU64 as TokenAmount,
+};
+use concordium_std::{
+    test_infrastructure::{MockFn, TestHost, TestStateBuilder},
+    CallContractError, ContractAddress, OwnedEntrypointName,
+};
+
+use crate::{errors::Error, helper::verify_product_token};
+
+use super::{
+    create_launch_pad, default_create_params, initialize_chain_and_contracts, mint_token,
+    view_launch_pad, view_state, OWNER, OWNER_TOKEN_ID, OWNER_TOKEN_URL, PRODUCT_NAME,
+};
+
+/// Verifies that the launch pad is only created for a product token of a
+/// CIS2 compliant contract held by the owner, and records the metadata URL
+/// of the token reported by the contract.
+#[test]
+fn create_verifies_product_token() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    // Launch pad contract itself does not implement CIS2
+    assert_eq!(
+        create_launch_pad(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            default_create_params(lp_contract),
+        ),
+        Err(Error::NotCis2)
+    );
+
+    // Product token is not minted yet, hence its balance query is
+    // rejected by the CIS2 contract
+    assert!(create_launch_pad(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        default_create_params(cis2_contract),
+    )
+    .is_err());
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    // Owner holds less than the allocated tokens
+    let mut params = default_create_params(cis2_contract);
+    params.product.allocated_tokens += 1.into();
+    assert_eq!(
+        create_launch_pad(&mut chain, lp_contract, OWNER, params),
+        Err(Error::Insufficient)
+    );
+    assert_eq!(
+        view_state(&mut chain, OWNER, lp_contract).total_launch_pads,
+        0
+    );
+
+    create_launch_pad(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        default_create_params(cis2_contract),
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(view.token_metadata.url, OWNER_TOKEN_URL);
+
+    Ok(())
+}
+
+/// Verifies that a contract supporting CIS2, but rejecting the `tokenMetadata`
+/// query of the product token, is reported as not CIS2 compliant.
+#[test]
+fn create_rejects_token_without_metadata() {
+    let cis2_contract = ContractAddress::new(1, 0);
+    let mut host = TestHost::new((), TestStateBuilder::new());
+
+    host.setup_mock_entrypoint(
+        cis2_contract,
+        OwnedEntrypointName::new_unchecked("supports".to_string()),
+        MockFn::new_v1(|_, _, _, _| {
+            Ok((
+                false,
+                SupportsQueryResponse {
+                    results: vec![SupportResult::Support],
+                },
+            ))
+        }),
+    );
+    host.setup_mock_entrypoint(
+        cis2_contract,
+        OwnedEntrypointName::new_unchecked("balanceOf".to_string()),
+        MockFn::new_v1(|_, _, _, _| Ok((false, BalanceOfQueryResponse(vec![TokenAmount(10000)])))),
+    );
+    host.setup_mock_entrypoint(
+        cis2_contract,
+        OwnedEntrypointName::new_unchecked("tokenMetadata".to_string()),
+        MockFn::returning_err::<()>(CallContractError::LogicReject {
+            reason: -42000001,
+            return_value: (),
+        }),
+    );
+
+    assert_eq!(
+        verify_product_token(&host, &default_create_params(cis2_contract).product),
+        Err(Error::NotCis2)
+    );
+}
@@ -0,0 +1,243 @@
+use concordium_cis2::{
+    AdditionalData, OnReceivingCis2Params, Receiver, TokenAmountU64 as TokenAmount, Transfer,
+    TransferParams,
+};
+use concordium_smart_contract_testing::{Chain, Energy, UpdateContractPayload};
+use concordium_std::{
+    to_bytes, Address, Amount, ContractAddress, OwnedEntrypointName, OwnedParameter,
+    OwnedReceiveName,
+};
+
+use crate::{
+    errors::Error,
+    params::{ApprovalParams, DepositData, VestParams},
+    state::Status,
+};
+
+use super::{
+    approve_launch_pad, create_launch_pad, default_create_params, get_token_balance,
+    initialize_chain_and_contracts, invest, mint_token, product_token_id, update_contract,
+    view_launch_pad, ADMIN, HOLDERS, OWNER, OWNER_TOKEN_ID, OWNER_TOKEN_URL, PRODUCT_NAME, SIGNER,
+};
+
+/// A helper function which returns the typed deposit payload for the launch
+/// pad of the product.
+fn deposit_data() -> Vec<u8> {
+    to_bytes(&DepositData::V1 {
+        product_name: PRODUCT_NAME.to_string(),
+    })
+}
+
+/// A helper function which returns the vesting params to buy the minimum
+/// tokens of the launch pad of the product.
+fn vest_params() -> VestParams {
+    VestParams {
+        launch_pad: PRODUCT_NAME.into(),
+        token_amount: 1000.into(),
+    }
+}
+
+/// A helper function which invokes the `Deposit` hook of the launch pad on
+/// behalf of the CIS2 contract, as if the given amount of product tokens was
+/// transfered along with the given additional data.
+fn invoke_deposit(
+    chain: &mut Chain,
+    amount: TokenAmount,
+    data: Vec<u8>,
+    cis2_contract: ContractAddress,
+    lp_contract: ContractAddress,
+) -> Result<(), Error> {
+    let payload = UpdateContractPayload {
+        amount: Amount::zero(),
+        address: lp_contract,
+        receive_name: OwnedReceiveName::new_unchecked("LaunchPad.Deposit".to_string()),
+        message: OwnedParameter::from_serial(&OnReceivingCis2Params {
+            token_id: product_token_id(),
+            amount,
+            from: Address::Account(OWNER),
+            data: AdditionalData::from(data),
+        })
+        .unwrap(),
+    };
+
+    chain.contract_update(
+        SIGNER,
+        OWNER,
+        Address::Contract(cis2_contract),
+        Energy::from(20000),
+        payload,
+    )?;
+
+    Ok(())
+}
+
+/// A helper function which transfers the given amount of product tokens to
+/// the launch pad through the `Deposit` hook.
+fn deposit_amount(
+    chain: &mut Chain,
+    amount: TokenAmount,
+    cis2_contract: ContractAddress,
+    lp_contract: ContractAddress,
+) -> Result<(), Error> {
+    update_contract::<_, ()>(
+        chain,
+        cis2_contract,
+        OWNER,
+        TransferParams(vec![Transfer {
+            token_id: OWNER_TOKEN_ID,
+            amount,
+            from: Address::Account(OWNER),
+            to: Receiver::Contract(
+                lp_contract,
+                OwnedEntrypointName::new_unchecked("Deposit".to_string()),
+            ),
+            data: AdditionalData::from(deposit_data()),
+        }]),
+        None,
+        "cis2_multi.transfer",
+    )
+}
+
+/// Verifies that the deposit is refused with a proper error if its payload is
+/// malformed, or if the launch pad is not yet approved.
+#[test]
+fn deposit_validates_payload_and_status() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    create_launch_pad(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        default_create_params(cis2_contract),
+    )?;
+
+    assert_eq!(
+        invoke_deposit(
+            &mut chain,
+            10000.into(),
+            deposit_data(),
+            cis2_contract,
+            lp_contract
+        ),
+        Err(Error::NotApproved)
+    );
+
+    approve_launch_pad(
+        &mut chain,
+        ADMIN,
+        ApprovalParams {
+            launch_pad: PRODUCT_NAME.into(),
+            approve: true,
+        },
+        lp_contract,
+    )?;
+
+    // Product name as raw bytes, or any other malformed payload, is
+    // rejected rather than trapping the contract
+    assert_eq!(
+        invoke_deposit(
+            &mut chain,
+            10000.into(),
+            PRODUCT_NAME.as_bytes().to_vec(),
+            cis2_contract,
+            lp_contract,
+        ),
+        Err(Error::Parse)
+    );
+    assert_eq!(
+        invoke_deposit(
+            &mut chain,
+            10001.into(),
+            deposit_data(),
+            cis2_contract,
+            lp_contract
+        ),
+        Err(Error::Limit)
+    );
+
+    Ok(())
+}
+
+/// Verifies that the partial deposits are accumulated, and the launch pad is
+/// only made LIVE, and open for vesting, once the allocated tokens are fully
+/// funded.
+#[test]
+fn deposit_partial_top_ups() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    create_launch_pad(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        default_create_params(cis2_contract),
+    )?;
+
+    approve_launch_pad(
+        &mut chain,
+        ADMIN,
+        ApprovalParams {
+            launch_pad: PRODUCT_NAME.into(),
+            approve: true,
+        },
+        lp_contract,
+    )?;
+
+    deposit_amount(&mut chain, 4000.into(), cis2_contract, lp_contract)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(view.status, Status::APPROVED);
+
+    // Partly funded launch pad does not accept vesting yet
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            vest_params(),
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        ),
+        Err(Error::JobFailed)
+    );
+
+    // Deposit exceeding the remaining allocation is refused
+    assert!(deposit_amount(&mut chain, 6001.into(), cis2_contract, lp_contract).is_err());
+
+    deposit_amount(&mut chain, 6000.into(), cis2_contract, lp_contract)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    let balance = get_token_balance(
+        &mut chain,
+        OWNER,
+        &[(lp_contract.into(), OWNER_TOKEN_ID)],
+        cis2_contract,
+    );
+
+    assert_eq!(view.status, Status::LIVE);
+    assert_eq!(balance.0[0], 10000.into());
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        vest_params(),
+        Amount::from_ccd(5 * 1000),
+        lp_contract,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use concordium_smart_contract_testing::{Chain, Energy, UpdateContractPayload};
+use concordium_std::{
+    schema::{self, SchemaType},
+    to_bytes, AccountAddress, Address, Amount, ContractAddress, Duration, OwnedParameter,
+    OwnedReceiveName, Serial, Timestamp,
+};
+
+use crate::{
+    errors::Error,
+    events::{CancelEvent, CliffEvent, Event, InvestEvent},
+    params::{LaunchPadKey, VestParams, WithdrawParams},
+};
+
+use super::{
+    default_create_params, initialize_chain_and_contracts, setup_live_launch_pad, HOLDERS, OWNER,
+    PRODUCT_NAME, SIGNER,
+};
+
+/// A helper function which invokes the given method in launch pad contract
+/// and returns the events logged by the launch pad.
+fn update_with_events<P: Serial>(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    params: P,
+    amount: Amount,
+    receive_name: &str,
+    contract: ContractAddress,
+) -> Result<Vec<Event>, Error> {
+    let payload = UpdateContractPayload {
+        amount,
+        address: contract,
+        receive_name: OwnedReceiveName::new_unchecked(receive_name.to_string()),
+        message: OwnedParameter::from_serial(&params).unwrap(),
+    };
+
+    let success = chain.contract_update(
+        SIGNER,
+        invoker,
+        Address::Account(invoker),
+        Energy::from(20000),
+        payload,
+    )?;
+
+    Ok(success
+        .events()
+        .filter(|(address, _)| *address == contract)
+        .flat_map(|(_, events)| events.iter())
+        .map(|event| event.parse().expect("[Error] Unable to parse event"))
+        .collect())
+}
+
+/// A helper function which invokes `Vest` method in launch pad contract and
+/// returns the events logged by the launch pad.
+fn invest_with_events(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    params: VestParams,
+    amount: Amount,
+    contract: ContractAddress,
+) -> Result<Vec<Event>, Error> {
+    update_with_events(chain, invoker, params, amount, "LaunchPad.Vest", contract)
+}
+
+/// Verifies that the tag of each serialized event refers to the schema of the
+/// same event, and the schema contains exactly the fields of the event.
+#[test]
+fn event_schema_matches_events() {
+    let events = match Event::get_type() {
+        schema::Type::TaggedEnum(events) => events,
+        _ => panic!("[Error] Event schema is not a tagged enum"),
+    };
+
+    let samples = [
+        (
+            Event::CLIFFSTARTED(CliffEvent {
+                launchpad_id: 0,
+                launchpad_name: PRODUCT_NAME.to_string(),
+                from: Timestamp::from_timestamp_millis(0),
+                to: Timestamp::from_timestamp_millis(1),
+            }),
+            "CliffEvent",
+            vec!["launchpad_id", "launchpad_name", "from", "to"],
+        ),
+        (
+            Event::INVESTED(InvestEvent {
+                launchpad_id: 0,
+                launchpad_name: PRODUCT_NAME.to_string(),
+                holder: HOLDERS[0],
+                round: 0,
+                token_amount: 1000.into(),
+                amount: Amount::from_ccd(5000),
+            }),
+            "InvestEvent",
+            vec![
+                "launchpad_id",
+                "launchpad_name",
+                "holder",
+                "round",
+                "token_amount",
+                "amount",
+            ],
+        ),
+        (
+            Event::CANCELED(CancelEvent {
+                launchpad_id: 0,
+                launchpad_name: PRODUCT_NAME.to_string(),
+                by: OWNER,
+                forced: false,
+            }),
+            "CancelEvent",
+            vec!["launchpad_id", "launchpad_name", "by", "forced"],
+        ),
+    ];
+
+    for (event, name, fields) in samples {
+        let tag = to_bytes(&event)[0];
+        let (schema_name, schema_fields) = events.get(&tag).expect("[Error] Missing event schema");
+
+        assert_eq!(schema_name, name);
+        match schema_fields {
+            schema::Fields::Named(schema_fields) => assert_eq!(
+                schema_fields
+                    .iter()
+                    .map(|(field, _)| field.as_str())
+                    .collect::<Vec<_>>(),
+                fields
+            ),
+            _ => panic!("[Error] Event fields are not named"),
+        }
+    }
+}
+
+/// Verifies that the investments are logged, along with the start of the
+/// cliff once the hard cap is reached.
+#[test]
+fn vest_logs_events() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for (holder, token_amount) in HOLDERS.iter().zip([2500u64, 2500, 2000]) {
+        let events = invest_with_events(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: token_amount.into(),
+            },
+            Amount::from_ccd(5 * token_amount),
+            lp_contract,
+        )?;
+
+        match events.first() {
+            Some(Event::INVESTED(event)) => {
+                assert_eq!(event.launchpad_id, 0);
+                assert_eq!(event.holder, *holder);
+                assert_eq!(event.token_amount, token_amount.into());
+                assert_eq!(event.amount, Amount::from_ccd(5 * token_amount));
+            }
+            _ => panic!("[Error] Investment is not logged"),
+        }
+
+        // Hard cap is reached with the last investment
+        assert_eq!(
+            events
+                .iter()
+                .any(|event| matches!(event, Event::CLIFFSTARTED(_))),
+            *holder == HOLDERS[2]
+        );
+    }
+
+    Ok(())
+}
+
+/// Verifies that the start of the cliff is logged once, by the first call
+/// after the vesting duration is over, rather than by the withdrawal only.
+#[test]
+fn cliff_logged_once_after_sale() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    // Soft cap is reached without reaching the hard cap
+    for holder in HOLDERS[..2].iter() {
+        let events = invest_with_events(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2500.into(),
+            },
+            Amount::from_ccd(5 * 2500),
+            lp_contract,
+        )?;
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::CLIFFSTARTED(_))));
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    // Owner reclaims the unsold tokens ahead of the withdrawal
+    let events = update_with_events(
+        &mut chain,
+        OWNER,
+        LaunchPadKey::from(PRODUCT_NAME),
+        Amount::zero(),
+        "LaunchPad.ReclaimTokens",
+        lp_contract,
+    )?;
+    match events.first() {
+        Some(Event::CLIFFSTARTED(event)) => {
+            assert_eq!(event.from, Timestamp::from_timestamp_millis(3000));
+        }
+        _ => panic!("[Error] Start of the cliff is not logged"),
+    }
+
+    let events = update_with_events(
+        &mut chain,
+        OWNER,
+        WithdrawParams {
+            launch_pad: PRODUCT_NAME.into(),
+            min_lp_tokens: 0.into(),
+            max_price_deviation: 100,
+        },
+        Amount::zero(),
+        "LaunchPad.WithdrawFunds",
+        lp_contract,
+    )?;
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, Event::WITHDRAWN(_))));
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, Event::CLIFFSTARTED(_))));
+
+    Ok(())
+}
@@ -0,0 +1,389 @@
+use concordium_cis2::{
+    OperatorUpdate, TokenAmountU64 as TokenAmount, UpdateOperator, UpdateOperatorParams,
+};
+use concordium_smart_contract_testing::Chain;
+use concordium_std::{Address, Amount, ContractAddress, Duration, Timestamp};
+
+use crate::{
+    dex::{AddLiquidityParams, ExchangeView, GetExchangeParams, TokenInfo},
+    errors::Error,
+    params::{CreateParams, RoundParams, VestParams, WithdrawParams},
+    state::{TimePeriod, VestingLimits},
+};
+
+use super::{
+    create_launch_pad, default_create_params, get_lp_token_balance, initialize_chain_and_contracts,
+    initialize_contract, invest, mint_token, product_token_id, read_contract,
+    setup_live_launch_pad, update_contract, view_launch_pad, view_state, ADMIN, HOLDERS, OWNER,
+    OWNER_TOKEN_ID, OWNER_TOKEN_URL, PRODUCT_NAME,
+};
+
+/// A helper function which invokes `WithdrawFunds` method in launch pad with
+/// the given bounds of the liquidity added to the DEX.
+fn withdraw_with_bounds(
+    chain: &mut Chain,
+    min_lp_tokens: TokenAmount,
+    max_price_deviation: u64,
+    contract: ContractAddress,
+) -> Result<(), Error> {
+    update_contract(
+        chain,
+        contract,
+        OWNER,
+        WithdrawParams {
+            launch_pad: PRODUCT_NAME.into(),
+            min_lp_tokens,
+            max_price_deviation,
+        },
+        None,
+        "LaunchPad.WithdrawFunds",
+    )
+}
+
+/// A helper function which invokes `getExchange` in DEX to view the pool of
+/// the product token from the perspective of the given holder.
+fn view_exchange(
+    chain: &mut Chain,
+    holder: Address,
+    cis2_contract: ContractAddress,
+    dex_contract: ContractAddress,
+) -> ExchangeView {
+    read_contract(
+        chain,
+        dex_contract,
+        OWNER,
+        GetExchangeParams {
+            holder,
+            token: TokenInfo {
+                id: product_token_id(),
+                address: cis2_contract,
+            },
+        },
+        "pixpel_swap.getExchange",
+    )
+}
+
+/// A helper function which sets up a live launch pad from the given params
+/// and invests into it until the hard cap is reached, so that the funds can
+/// be withdrawn.
+fn setup_finished_launch_pad(
+    chain: &mut Chain,
+    lp_contract: ContractAddress,
+    cis2_contract: ContractAddress,
+    params: CreateParams,
+) -> Result<(), Error> {
+    setup_live_launch_pad(chain, lp_contract, cis2_contract, params)?;
+
+    for (holder, token_amount) in HOLDERS.iter().zip([2500u64, 2500, 2000]) {
+        invest(
+            chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: token_amount.into(),
+            },
+            Amount::from_ccd(5 * token_amount),
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    Ok(())
+}
+
+/// Verifies that the withdrawal fails when the liquidity yields less LPTokens
+/// than expected, and that the LPTokens received are fully distributed
+/// between the platform, the owner and the holders.
+#[test]
+fn withdraw_bounds_lp_tokens() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, dex_contract) = initialize_chain_and_contracts();
+
+    setup_finished_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    assert_eq!(
+        withdraw_with_bounds(&mut chain, u64::MAX.into(), 100, lp_contract),
+        Err(Error::Slippage)
+    );
+
+    withdraw_with_bounds(&mut chain, 1.into(), 100, lp_contract)?;
+
+    let exchange = view_exchange(
+        &mut chain,
+        Address::Contract(lp_contract),
+        cis2_contract,
+        dex_contract,
+    );
+    let admin_lp_tokens = get_lp_token_balance(
+        &mut chain,
+        OWNER,
+        &[(ADMIN.into(), exchange.lp_token_id)],
+        dex_contract,
+    )
+    .0[0];
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    let (_, holders_lp_tokens) = view
+        .release_schedule
+        .and_then(|schedule| schedule.liquidity)
+        .expect("Liquidity is released to the holders");
+    let owner_lp_tokens: u64 = view
+        .locked_release
+        .iter()
+        .map(|(_, locked)| (locked.0).0 .0)
+        .sum();
+
+    assert!(admin_lp_tokens.0 > 0);
+    assert_eq!(
+        exchange.lp_tokens_holder_balance,
+        holders_lp_tokens + owner_lp_tokens.into()
+    );
+    assert_eq!(
+        exchange.lp_tokens_supply,
+        exchange.lp_tokens_holder_balance + admin_lp_tokens
+    );
+
+    Ok(())
+}
+
+/// Verifies that the liquidity is not added to an existing pool whose price
+/// deviates from the liquidity price of the product token beyond the given bound.
+#[test]
+fn withdraw_rejects_skewed_pool() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, dex_contract) = initialize_chain_and_contracts();
+
+    setup_finished_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    // Pool of the product token is created ahead of the withdrawal, at a
+    // price of 1 CCD instead of the liquidity price of 5 CCD
+    mint_token(
+        &mut chain,
+        HOLDERS[2],
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        cis2_contract,
+        HOLDERS[2],
+        UpdateOperatorParams(vec![UpdateOperator {
+            update: OperatorUpdate::Add,
+            operator: dex_contract.into(),
+        }]),
+        None,
+        "cis2_multi.updateOperator",
+    )?;
+
+    update_contract::<_, ()>(
+        &mut chain,
+        dex_contract,
+        HOLDERS[2],
+        AddLiquidityParams {
+            token: TokenInfo {
+                id: product_token_id(),
+                address: cis2_contract,
+            },
+            token_amount: 1000.into(),
+        },
+        Some(Amount::from_ccd(1000)),
+        "pixpel_swap.addLiquidity",
+    )?;
+
+    assert_eq!(
+        withdraw_with_bounds(&mut chain, 0.into(), 100, lp_contract),
+        Err(Error::PriceDeviation)
+    );
+    assert!(!view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract).withdrawn);
+
+    Ok(())
+}
+
+/// Verifies that a launch pad can only pick a DEX registered by the admin,
+/// and its liquidity is locked in the picked DEX rather than the platform DEX.
+#[test]
+fn withdraw_to_registered_dex() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    let other_dex = initialize_contract(
+        &mut chain,
+        "../nft-auction/test-build-artifacts/pixpel_swap.wasm.v1",
+        "pixpel_swap",
+        (),
+    );
+
+    let mut params = default_create_params(cis2_contract);
+    params.dex = Some(other_dex);
+
+    assert_eq!(
+        create_launch_pad(&mut chain, lp_contract, OWNER, params),
+        Err(Error::UnknownDex)
+    );
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            other_dex,
+            None,
+            "LaunchPad.RegisterDex"
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        other_dex,
+        None,
+        "LaunchPad.RegisterDex",
+    )?;
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            ADMIN,
+            other_dex,
+            None,
+            "LaunchPad.RegisterDex"
+        ),
+        Err(Error::Taken)
+    );
+    assert_eq!(
+        view_state(&mut chain, ADMIN, lp_contract).dexes,
+        vec![other_dex]
+    );
+
+    let mut params = default_create_params(cis2_contract);
+    params.dex = Some(other_dex);
+    setup_finished_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    withdraw_with_bounds(&mut chain, 1.into(), 100, lp_contract)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    let exchange = view_exchange(
+        &mut chain,
+        Address::Contract(lp_contract),
+        cis2_contract,
+        other_dex,
+    );
+
+    assert_eq!(view.dex, other_dex);
+    assert!(view.withdrawn);
+    assert!(exchange.lp_tokens_holder_balance.0 > 0);
+
+    // Unregistered DEX can no longer be picked by the new launch pads
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        other_dex,
+        None,
+        "LaunchPad.UnregisterDex",
+    )?;
+    assert!(view_state(&mut chain, ADMIN, lp_contract).dexes.is_empty());
+
+    let mut params = default_create_params(cis2_contract);
+    params.product.name = "Another Product".to_string();
+    params.dex = Some(other_dex);
+    assert_eq!(
+        create_launch_pad(&mut chain, lp_contract, OWNER, params),
+        Err(Error::UnknownDex)
+    );
+
+    Ok(())
+}
+
+/// Verifies that the liquidity is added at the token price of the last sale
+/// round, rather than the product token price.
+#[test]
+fn withdraw_at_last_round_price() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, dex_contract) = initialize_chain_and_contracts();
+
+    let mut params = default_create_params(cis2_contract);
+    params.rounds = vec![
+        RoundParams {
+            timeperiod: TimePeriod {
+                start: Timestamp::from_timestamp_millis(0),
+                end: Timestamp::from_timestamp_millis(1000),
+            },
+            token_price: Amount::from_ccd(4),
+            allocated_tokens: TokenAmount(2500),
+            vest_limits: VestingLimits {
+                min: TokenAmount(1000),
+                max: TokenAmount(2500),
+            },
+        },
+        RoundParams {
+            timeperiod: TimePeriod {
+                start: Timestamp::from_timestamp_millis(1500),
+                end: Timestamp::from_timestamp_millis(3000),
+            },
+            token_price: Amount::from_ccd(8),
+            allocated_tokens: TokenAmount(2500),
+            vest_limits: VestingLimits {
+                min: TokenAmount(1000),
+                max: TokenAmount(1500),
+            },
+        },
+    ];
+
+    setup_live_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    let vest_params = |token_amount: u64| VestParams {
+        launch_pad: PRODUCT_NAME.into(),
+        token_amount: token_amount.into(),
+    };
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        vest_params(2500),
+        Amount::from_ccd(4 * 2500),
+        lp_contract,
+    )?;
+
+    let _ = chain.tick_block_time(Duration::from_millis(1500));
+
+    for (holder, token_amount) in HOLDERS[1..].iter().zip([1500u64, 1000]) {
+        invest(
+            &mut chain,
+            *holder,
+            vest_params(token_amount),
+            Amount::from_ccd(8 * token_amount),
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(2000));
+
+    withdraw_with_bounds(&mut chain, 1.into(), 100, lp_contract)?;
+
+    // 40% of the 30000 CCD raised is paired with the product tokens at the
+    // public round price of 8 CCD
+    let exchange = view_exchange(
+        &mut chain,
+        Address::Contract(lp_contract),
+        cis2_contract,
+        dex_contract,
+    );
+    assert_eq!(
+        exchange.ccd_balance,
+        Amount::from_ccd(12000).micro_ccd.into()
+    );
+    assert_eq!(exchange.token_balance, TokenAmount(1500));
+
+    Ok(())
+}
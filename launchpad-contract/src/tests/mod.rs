@@ -1,24 +1,46 @@
 use crate::{
     errors::Error,
-    params::{ApprovalParams, ClaimLockedParams, ClaimUnLockedParams, CreateParams, VestParams},
-    response::LaunchPadView,
-    state::Admin,
+    params::LockupDetails,
+    params::{
+        ApprovalParams, ClaimLockedParams, ClaimUnLockedParams, CreateParams, DepositData,
+        LaunchPadKey, VestParams, WithdrawParams,
+    },
+    response::{LaunchPadView, StateView},
+    state::{Admin, LiquidityDetails, Product, TimePeriod, VestingLimits, STATE_VERSION},
 };
 use concordium_cis2::{
     AdditionalData, BalanceOfQuery, BalanceOfQueryParams, BalanceOfQueryResponse, Receiver,
-    TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdU8 as TokenID, Transfer, TransferParams,
+    TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdU8 as TokenID, TokenIdVec, Transfer,
+    TransferParams,
 };
 use concordium_smart_contract_testing::{
     module_load_v1, Account, AccountKeys, Chain, Energy, InitContractPayload, Signer,
     UpdateContractPayload,
 };
 use concordium_std::{
-    AccountAddress, AccountBalance, Address, Amount, ContractAddress, Deserial, MetadataUrl,
-    OwnedContractName, OwnedEntrypointName, OwnedParameter, OwnedReceiveName, SchemaType, Serial,
-    ACCOUNT_ADDRESS_SIZE,
+    to_bytes, AccountAddress, AccountBalance, Address, Amount, ContractAddress, Deserial, Duration,
+    MetadataUrl, OwnedContractName, OwnedEntrypointName, OwnedParameter, OwnedReceiveName,
+    SchemaType, Serial, Timestamp, ACCOUNT_ADDRESS_SIZE,
 };
-
+use std::{fs, path::Path, time::SystemTime};
+
+mod admin;
+#[allow(deprecated)]
+mod claim;
+#[allow(deprecated)]
+mod create;
+mod deposit;
+mod events;
+mod liquidity;
+mod payment;
+mod reclaim;
+mod refund;
+mod roles;
 mod smoke;
+#[allow(deprecated)]
+mod venue;
+mod vest;
+mod views;
 
 /// Dummy signer which always signs with one key
 const SIGNER: Signer = Signer::with_one_key();
@@ -29,7 +51,7 @@ const ACC_INITIAL_BALANCE: Amount = Amount::from_ccd(20000);
 const ADMIN: AccountAddress = AccountAddress([1; ACCOUNT_ADDRESS_SIZE]);
 const OWNER: AccountAddress = AccountAddress([2; ACCOUNT_ADDRESS_SIZE]);
 
-const HOLDERS: &'static [AccountAddress] = &[
+const HOLDERS: &[AccountAddress] = &[
     AccountAddress([3; ACCOUNT_ADDRESS_SIZE]),
     AccountAddress([4; ACCOUNT_ADDRESS_SIZE]),
     AccountAddress([5; ACCOUNT_ADDRESS_SIZE]),
@@ -40,13 +62,27 @@ const LIQUID_SHARE: u64 = 2;
 const ALLOC_SHARE: u64 = 1;
 
 const OWNER_TOKEN_ID: TokenID = TokenID(1);
+/// Product token ID of the owner as listed in the launch pad, which stores
+/// the CIS2 token IDs of any length
+fn product_token_id() -> TokenIdVec {
+    TokenIdVec(vec![OWNER_TOKEN_ID.0])
+}
+
+/// Token minted by the holders to be used as the stablecoin
+const PAYMENT_TOKEN_ID: TokenID = TokenID(2);
+
 const OWNER_TOKEN_URL: &str = "http://some.example/token/0";
 
+const PRODUCT_NAME: &str = "Pixpel Market-Place";
+
+/// Launch pad module built from the contract sources
+const LAUNCH_PAD_MODULE: &str = "build/launchpad.wasm.v1";
+
 /// A helper function to setup and initialize the concordium block-chain and deploy the contracts as mocks
 /// for unit testing.
 ///
-/// It is required to build the `Dex`, `LaunchPad`, `Cis2_multi` contracts in the root as `build/auction.wasm.v1` and
-/// cis2_multi build should be present in path `test-build-artifacts/cis2multi.wasm.v1`
+/// It is required to build the `LaunchPad` contract from the current sources as `build/launchpad.wasm.v1`, and the
+/// cis2_multi and pixpel_swap builds should be present in `../nft-auction/test-build-artifacts`
 pub fn initialize_chain_and_contracts() -> (
     Chain,
     AccountKeys,
@@ -91,15 +127,17 @@ pub fn initialize_chain_and_contracts() -> (
     // Load and deploy the DEX (Pixpel swap) module.
     let dex_contract = initialize_contract(
         &mut chain,
-        "../nft-auction/test-build-artifacts/pixpel_swap.wasm.v1".into(),
-        "pixpel_swap".into(),
+        "../nft-auction/test-build-artifacts/pixpel_swap.wasm.v1",
+        "pixpel_swap",
         (),
     );
 
-    // Load and deploy the main Launch Pad module.
+    // Load and deploy the main Launch Pad module, which must be built from
+    // the current sources for the tests to exercise them.
+    ensure_module_up_to_date(LAUNCH_PAD_MODULE);
     let launch_pad_contract = initialize_contract(
         &mut chain,
-        "build/launchpad.wasm.v1",
+        LAUNCH_PAD_MODULE,
         "LaunchPad",
         Admin {
             address: ADMIN,
@@ -109,6 +147,11 @@ pub fn initialize_chain_and_contracts() -> (
             dex_address: dex_contract,
         },
     );
+    assert_eq!(
+        view_state(&mut chain, ADMIN, launch_pad_contract).version,
+        STATE_VERSION,
+        "{LAUNCH_PAD_MODULE} is outdated, rebuild it with `cargo concordium build --out {LAUNCH_PAD_MODULE}`"
+    );
 
     (
         chain,
@@ -119,6 +162,39 @@ pub fn initialize_chain_and_contracts() -> (
     )
 }
 
+/// A helper function which panics if the launch pad module at the given path
+/// is older than any of the contract sources, as the integration tests would
+/// otherwise run against a stale build of the contract.
+fn ensure_module_up_to_date(module_path: &str) {
+    fn latest_modified(dir: &Path) -> SystemTime {
+        fs::read_dir(dir)
+            .expect("Should be able to read the sources")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_name() != "tests")
+            .map(|entry| {
+                let path = entry.path();
+                if path.is_dir() {
+                    latest_modified(&path)
+                } else {
+                    entry
+                        .metadata()
+                        .and_then(|metadata| metadata.modified())
+                        .expect("Should be able to read the source modification time")
+                }
+            })
+            .max()
+            .unwrap_or(SystemTime::UNIX_EPOCH)
+    }
+
+    let built = fs::metadata(module_path)
+        .and_then(|metadata| metadata.modified())
+        .unwrap_or_else(|_| panic!("{module_path} is missing, build it with `cargo concordium build --out {module_path}`"));
+    assert!(
+        built >= latest_modified(Path::new("src")),
+        "{module_path} is older than the contract sources, rebuild it with `cargo concordium build --out {module_path}`"
+    );
+}
+
 fn initialize_contract<P>(
     chain: &mut Chain,
     module_path: &str,
@@ -129,15 +205,21 @@ where
     P: Serial,
 {
     let module = module_load_v1(module_path).expect("[Error] Unable to load module");
-    let deploy = chain
-        .module_deploy_v1(SIGNER, ADMIN, module)
-        .expect("[Error] Unable to deploy");
+    let mod_ref = module.get_module_ref();
+
+    // Module is only deployed once, so that more instances of the
+    // contract can be initialized from it
+    if chain.get_module(mod_ref).is_none() {
+        chain
+            .module_deploy_v1(SIGNER, ADMIN, module)
+            .expect("[Error] Unable to deploy");
+    }
 
     let owned_params = OwnedParameter::from_serial(&init_params).unwrap();
 
     let payload = InitContractPayload {
         amount: Amount::zero(),
-        mod_ref: deploy.module_reference,
+        mod_ref,
         init_name: OwnedContractName::new_unchecked(format!("init_{}", contract_name)),
         param: owned_params,
     };
@@ -325,6 +407,136 @@ pub fn get_lp_token_balance(
     )
 }
 
+/// A helper function which returns the launch pad creation params used
+/// across the tests.
+///
+/// Product token is priced at 5 CCD, soft cap is set to 5000 tokens and
+/// hard cap is set to 7000 tokens worth of CCD.
+fn default_create_params(cis2_contract: ContractAddress) -> CreateParams {
+    CreateParams {
+        product: Product {
+            name: PRODUCT_NAME.to_string(),
+            owner: OWNER,
+            token_id: product_token_id(),
+            token_price: Amount::from_ccd(5),
+            allocated_tokens: TokenAmount(10000),
+            cis2_contract,
+        },
+        timeperiod: TimePeriod {
+            start: Timestamp::from_timestamp_millis(0),
+            end: Timestamp::from_timestamp_millis(3000),
+        },
+        soft_cap: Amount::from_ccd(5 * 5000),
+        hard_cap: Some(Amount::from_ccd(5 * 7000)),
+        vest_limits: VestingLimits {
+            min: TokenAmount(1000),
+            max: TokenAmount(2500),
+        },
+        lockup_details: LockupDetails {
+            cliff: 3,
+            release_cycles: 3,
+        },
+        liquidity_details: LiquidityDetails {
+            liquidity_allocation: 40,
+            release_cycles: 3,
+        },
+        allowlist_end: None,
+        rounds: vec![],
+        payment_token: None,
+        dex: None,
+    }
+}
+
+/// A helper function which mints the product tokens for the owner, then
+/// creates, approves and deposits the tokens for the launch pad created
+/// from the given params. Launch pad is LIVE for vesting afterwards.
+fn setup_live_launch_pad(
+    chain: &mut Chain,
+    lp_contract: ContractAddress,
+    cis2_contract: ContractAddress,
+    params: CreateParams,
+) -> Result<(), Error> {
+    let product_name = params.product.name.clone();
+
+    mint_token(
+        chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    create_launch_pad(chain, lp_contract, OWNER, params)?;
+
+    approve_launch_pad(
+        chain,
+        ADMIN,
+        ApprovalParams {
+            launch_pad: product_name.clone().into(),
+            approve: true,
+        },
+        lp_contract,
+    )?;
+
+    deposit_tokens(chain, OWNER, product_name, cis2_contract, lp_contract)
+}
+
+/// A helper function which invokes `RefundInvestment` method in launch pad. This
+/// method is invoked by the holder to get the invested amount back from a canceled
+/// or failed launch pad.
+fn refund_investment(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    launch_pad: impl Into<LaunchPadKey>,
+    contract: ContractAddress,
+) -> Result<(), Error> {
+    update_contract(
+        chain,
+        contract,
+        invoker,
+        launch_pad.into(),
+        None,
+        "LaunchPad.RefundInvestment",
+    )
+}
+
+/// A helper function which invokes `ReclaimTokens` method in launch pad. This
+/// method is invoked by the product owner to get back the unsold tokens or the
+/// deposited tokens of a failed launch pad.
+fn reclaim_tokens(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    launch_pad: impl Into<LaunchPadKey>,
+    contract: ContractAddress,
+) -> Result<(), Error> {
+    update_contract(
+        chain,
+        contract,
+        invoker,
+        launch_pad.into(),
+        None,
+        "LaunchPad.ReclaimTokens",
+    )
+}
+
+/// A helper function which invokes `CancelLaunchPad` method in launch pad. This
+/// method is invoked by the product owner to cancel the launch pad.
+fn cancel_launch_pad(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    launch_pad: impl Into<LaunchPadKey>,
+    contract: ContractAddress,
+) -> Result<(), Error> {
+    update_contract(
+        chain,
+        contract,
+        invoker,
+        launch_pad.into(),
+        None,
+        "LaunchPad.CancelLaunchPad",
+    )
+}
+
 /// A helper function which invokes `ClaimLockedTokens` method in launch pad. This
 /// method is invoked by the either the owner or holder to claim their locked funds
 /// in liquidity pool as LPTokens.
@@ -368,14 +580,18 @@ fn claim_tokens(
 fn withdraw_raised_funds(
     chain: &mut Chain,
     invoker: AccountAddress,
-    params: String,
+    launch_pad: impl Into<LaunchPadKey>,
     contract: ContractAddress,
 ) -> Result<(), Error> {
     update_contract(
         chain,
         contract,
         invoker,
-        params,
+        WithdrawParams {
+            launch_pad: launch_pad.into(),
+            min_lp_tokens: 0.into(),
+            max_price_deviation: 100,
+        },
         None,
         "LaunchPad.WithdrawFunds",
     )
@@ -398,12 +614,43 @@ fn invest(
         "LaunchPad.Vest",
     )
 }
+/// A helper function which invokes `cis2 transfer` to pay with the given token,
+/// which in turns invokes the `VestCis2` method in launch pad.
+fn invest_with_token(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    params: VestParams,
+    payment: (TokenID, TokenAmount),
+    cis2_contract: ContractAddress,
+    launch_pad_contract: ContractAddress,
+) -> Result<(), Error> {
+    let transfer_params = TransferParams(vec![Transfer {
+        token_id: payment.0,
+        amount: payment.1,
+        from: Address::Account(invoker),
+        to: Receiver::Contract(
+            launch_pad_contract,
+            OwnedEntrypointName::new_unchecked("VestCis2".to_string()),
+        ),
+        data: AdditionalData::from(to_bytes(&params)),
+    }]);
+
+    update_contract::<_, ()>(
+        chain,
+        cis2_contract,
+        invoker,
+        transfer_params,
+        None,
+        "cis2_multi.transfer",
+    )
+}
+
 /// A helper function which invokes `cis2 transfer`, which in turns invokes the
 /// "Depsoit" method in launch pad.
 fn deposit_tokens(
     chain: &mut Chain,
     invoker: AccountAddress,
-    product_name: String,
+    launch_pad: impl Into<LaunchPadKey>,
     cis2_contract: ContractAddress,
     launch_pad_contract: ContractAddress,
 ) -> Result<(), Error> {
@@ -415,7 +662,9 @@ fn deposit_tokens(
             launch_pad_contract,
             OwnedEntrypointName::new_unchecked("Deposit".to_string()),
         ),
-        data: AdditionalData::from(product_name.as_bytes().to_owned()),
+        data: AdditionalData::from(to_bytes(&DepositData::V2 {
+            launch_pad: launch_pad.into(),
+        })),
     }]);
 
     update_contract::<_, ()>(
@@ -446,6 +695,21 @@ fn approve_launch_pad(
     )
 }
 
+/// A helper function to invoke `viewState` in launch pad to get the core
+/// state of the contract.
+///
+/// Returns the `StateView` type or panics with error message
+fn view_state(chain: &mut Chain, invoker: AccountAddress, contract: ContractAddress) -> StateView {
+    read_contract(chain, contract, invoker, (), "LaunchPad.viewState")
+}
+
+/// A helper function which ticks the block time of the chain until the given
+/// timestamp, such as the end of the cliff or of a release cycle.
+fn tick_until(chain: &mut Chain, timestamp: Timestamp) {
+    let duration = timestamp.millis.saturating_sub(chain.block_time().millis);
+    let _ = chain.tick_block_time(Duration::from_millis(duration));
+}
+
 /// A helper function to invoke `viewLauchPad` in launch pad to get a specefic
 /// launch pad current state in the contract
 ///
@@ -453,14 +717,14 @@ fn approve_launch_pad(
 fn view_launch_pad(
     chain: &mut Chain,
     invoker: AccountAddress,
-    product_name: String,
+    launch_pad: impl Into<LaunchPadKey>,
     contract: ContractAddress,
 ) -> LaunchPadView {
     read_contract(
         chain,
         contract,
         invoker,
-        product_name,
+        launch_pad.into(),
         "LaunchPad.viewLaunchPad",
     )
 }
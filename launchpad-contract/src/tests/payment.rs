@@ -0,0 +1,212 @@
+use concordium_cis2::{TokenAmountU64 as TokenAmount, TokenIdU8 as TokenID, TokenIdVec};
+use concordium_smart_contract_testing::Chain;
+use concordium_std::{Address, Amount, ContractAddress, Duration};
+
+use crate::{
+    errors::Error,
+    params::{CreateParams, VestParams},
+    state::PaymentToken,
+};
+
+use super::{
+    create_launch_pad, default_create_params, get_token_balance, initialize_chain_and_contracts,
+    invest, invest_with_token, mint_token, refund_investment, setup_live_launch_pad,
+    view_launch_pad, withdraw_raised_funds, HOLDERS, OWNER, OWNER_TOKEN_URL, PAYMENT_TOKEN_ID,
+    PRODUCT_NAME,
+};
+
+/// A helper function which returns the launch pad creation params accepting
+/// the payment token, where the product token is priced at 2 units of the
+/// payment token.
+fn payment_create_params(cis2_contract: ContractAddress) -> CreateParams {
+    let mut params = default_create_params(cis2_contract);
+    params.product.token_price = Amount::from_micro_ccd(2);
+    params.soft_cap = Amount::from_micro_ccd(2 * 5000);
+    params.hard_cap = Some(Amount::from_micro_ccd(2 * 7000));
+    params.liquidity_details.liquidity_allocation = 0;
+    params.payment_token = Some(PaymentToken {
+        contract: cis2_contract,
+        token_id: TokenIdVec(vec![PAYMENT_TOKEN_ID.0]),
+    });
+    params
+}
+
+fn vest_params(token_amount: u64) -> VestParams {
+    VestParams {
+        launch_pad: PRODUCT_NAME.into(),
+        token_amount: token_amount.into(),
+    }
+}
+
+/// A helper function to get the payment token balance of the given address
+fn payment_balance(
+    chain: &mut Chain,
+    address: Address,
+    cis2_contract: ContractAddress,
+) -> TokenAmount {
+    get_token_balance(chain, OWNER, &[(address, PAYMENT_TOKEN_ID)], cis2_contract).0[0]
+}
+
+/// Verifies that the launch pad accepting a payment token is invested in
+/// that token with the excess refunded, and the raised funds are withdrawn
+/// by the owner in that token.
+#[test]
+fn payment_token_vest_and_withdraw() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    // Launch pads paid in a CIS2 token can not allocate funds for liquidity
+    let mut params = payment_create_params(cis2_contract);
+    params.liquidity_details.liquidity_allocation = 40;
+    assert_eq!(
+        create_launch_pad(&mut chain, lp_contract, OWNER, params),
+        Err(Error::TokenLiquidity)
+    );
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        payment_create_params(cis2_contract),
+    )?;
+
+    for holder in HOLDERS.iter() {
+        mint_token(
+            &mut chain,
+            *holder,
+            cis2_contract,
+            PAYMENT_TOKEN_ID,
+            OWNER_TOKEN_URL.to_string(),
+        );
+    }
+
+    // Investing in CCD is not accepted
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            vest_params(2500),
+            Amount::from_ccd(5 * 2500),
+            lp_contract,
+        ),
+        Err(Error::InCorrect)
+    );
+
+    // Paying with a token other than the payment token is not accepted.
+    // Rejections of the hook are propagated as CIS2 contract errors
+    mint_token(
+        &mut chain,
+        HOLDERS[2],
+        cis2_contract,
+        TokenID(3),
+        OWNER_TOKEN_URL.to_string(),
+    );
+    assert!(invest_with_token(
+        &mut chain,
+        HOLDERS[2],
+        vest_params(2500),
+        (TokenID(3), TokenAmount(2 * 2500)),
+        cis2_contract,
+        lp_contract,
+    )
+    .is_err());
+
+    // Paying less than the price is rejected
+    assert!(invest_with_token(
+        &mut chain,
+        HOLDERS[0],
+        vest_params(2500),
+        (PAYMENT_TOKEN_ID, TokenAmount(2500)),
+        cis2_contract,
+        lp_contract,
+    )
+    .is_err());
+
+    // Excess paid is refunded in the payment token
+    invest_with_token(
+        &mut chain,
+        HOLDERS[0],
+        vest_params(2500),
+        (PAYMENT_TOKEN_ID, TokenAmount(2 * 2500 + 1000)),
+        cis2_contract,
+        lp_contract,
+    )?;
+    assert_eq!(
+        payment_balance(&mut chain, HOLDERS[0].into(), cis2_contract),
+        TokenAmount(10000 - 2 * 2500)
+    );
+
+    invest_with_token(
+        &mut chain,
+        HOLDERS[1],
+        vest_params(2500),
+        (PAYMENT_TOKEN_ID, TokenAmount(2 * 2500)),
+        cis2_contract,
+        lp_contract,
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(view.raised, Amount::from_micro_ccd(2 * 5000));
+    assert_eq!(chain.contract_balance(lp_contract), Some(Amount::zero()));
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    assert_eq!(
+        payment_balance(&mut chain, OWNER.into(), cis2_contract),
+        TokenAmount(2 * 5000)
+    );
+    assert_eq!(
+        payment_balance(&mut chain, lp_contract.into(), cis2_contract),
+        TokenAmount(0)
+    );
+
+    Ok(())
+}
+
+/// Verifies that the holders of a failed launch pad get their investment
+/// back in the payment token.
+#[test]
+fn payment_token_refund() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        payment_create_params(cis2_contract),
+    )?;
+
+    mint_token(
+        &mut chain,
+        HOLDERS[0],
+        cis2_contract,
+        PAYMENT_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    invest_with_token(
+        &mut chain,
+        HOLDERS[0],
+        vest_params(2000),
+        (PAYMENT_TOKEN_ID, TokenAmount(2 * 2000)),
+        cis2_contract,
+        lp_contract,
+    )?;
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    refund_investment(
+        &mut chain,
+        HOLDERS[0],
+        PRODUCT_NAME.to_string(),
+        lp_contract,
+    )?;
+
+    assert_eq!(
+        payment_balance(&mut chain, HOLDERS[0].into(), cis2_contract),
+        TokenAmount(10000)
+    );
+
+    Ok(())
+}
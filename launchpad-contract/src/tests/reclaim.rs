@@ -0,0 +1,112 @@
+use concordium_cis2::TokenAmountU64 as TokenAmount;
+use concordium_std::{Amount, Duration};
+
+use crate::{errors::Error, params::VestParams};
+
+use super::{
+    cancel_launch_pad, default_create_params, get_token_balance, initialize_chain_and_contracts,
+    invest, reclaim_tokens, setup_live_launch_pad, ADMIN, HOLDERS, OWNER, OWNER_TOKEN_ID,
+    PRODUCT_NAME,
+};
+
+/// Verifies that the product owner gets back all the deposited tokens
+/// once the launch pad is canceled.
+#[test]
+fn reclaim_canceled_launch_pad() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    // Tokens can not be reclaimed while the launch pad is live
+    assert_eq!(
+        reclaim_tokens(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract),
+        Err(Error::JobFailed)
+    );
+
+    cancel_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    assert_eq!(
+        reclaim_tokens(
+            &mut chain,
+            HOLDERS[0],
+            PRODUCT_NAME.to_string(),
+            lp_contract
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    reclaim_tokens(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    let balances = get_token_balance(
+        &mut chain,
+        OWNER,
+        &[
+            (OWNER.into(), OWNER_TOKEN_ID),
+            (lp_contract.into(), OWNER_TOKEN_ID),
+        ],
+        cis2_contract,
+    );
+
+    assert_eq!(balances.0, vec![TokenAmount(10000), TokenAmount(0)]);
+    assert_eq!(
+        reclaim_tokens(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract),
+        Err(Error::Claimed)
+    );
+
+    Ok(())
+}
+
+/// Verifies that the product owner only gets back the unsold tokens once
+/// the launch pad is finished after reaching the soft cap, while the tokens
+/// sold to holders and the allocation cut of the platform are kept aside.
+#[test]
+fn reclaim_unsold_tokens() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for (holder, tokens) in HOLDERS.iter().zip([1000, 2000, 2200]) {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: tokens.into(),
+            },
+            Amount::from_ccd(5 * tokens),
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    reclaim_tokens(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    let balances = get_token_balance(
+        &mut chain,
+        OWNER,
+        &[
+            (OWNER.into(), OWNER_TOKEN_ID),
+            (ADMIN.into(), OWNER_TOKEN_ID),
+            (lp_contract.into(), OWNER_TOKEN_ID),
+        ],
+        cis2_contract,
+    );
+
+    assert_eq!(
+        balances.0,
+        vec![TokenAmount(4700), TokenAmount(100), TokenAmount(5200)]
+    );
+
+    Ok(())
+}
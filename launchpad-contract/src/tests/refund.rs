@@ -0,0 +1,237 @@
+use concordium_cis2::TokenAmountU64 as TokenAmount;
+use concordium_std::{Amount, Duration};
+
+use crate::{
+    errors::Error,
+    params::{LaunchPadKey, VestParams},
+    state::Status,
+};
+
+use super::{
+    cancel_launch_pad, default_create_params, get_token_balance, initialize_chain_and_contracts,
+    invest, reclaim_tokens, refund_investment, setup_live_launch_pad, update_contract,
+    view_launch_pad, ADMIN, HOLDERS, OWNER, OWNER_TOKEN_ID, PRODUCT_NAME,
+};
+
+/// Verifies that the holders get their investment back once the owner
+/// cancels the launch pad, and that a holder can not be refunded twice.
+#[test]
+fn refund_canceled_launch_pad() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for holder in HOLDERS[..2].iter() {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 1000.into(),
+            },
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        )?;
+    }
+
+    // Investments can not be refunded while the launch pad is live
+    assert_eq!(
+        refund_investment(
+            &mut chain,
+            HOLDERS[0],
+            PRODUCT_NAME.to_string(),
+            lp_contract
+        ),
+        Err(Error::JobFailed)
+    );
+
+    cancel_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    refund_investment(
+        &mut chain,
+        HOLDERS[0],
+        PRODUCT_NAME.to_string(),
+        lp_contract,
+    )?;
+
+    assert_eq!(
+        chain.contract_balance(lp_contract),
+        Some(Amount::from_ccd(5 * 1000))
+    );
+    assert_eq!(
+        refund_investment(
+            &mut chain,
+            HOLDERS[0],
+            PRODUCT_NAME.to_string(),
+            lp_contract
+        ),
+        Err(Error::Claimed)
+    );
+    assert_eq!(
+        refund_investment(
+            &mut chain,
+            HOLDERS[2],
+            PRODUCT_NAME.to_string(),
+            lp_contract
+        ),
+        Err(Error::NotFound)
+    );
+
+    refund_investment(
+        &mut chain,
+        HOLDERS[1],
+        PRODUCT_NAME.to_string(),
+        lp_contract,
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+
+    assert_eq!(view.status, Status::CANCELED);
+    assert_eq!(view.raised, Amount::zero());
+    assert!(view.holders.iter().all(|(_, holder)| holder.refunded));
+    assert_eq!(chain.contract_balance(lp_contract), Some(Amount::zero()));
+
+    Ok(())
+}
+
+/// Verifies that the holders get their investment back once the launch
+/// pad finishes without reaching the soft cap.
+#[test]
+fn refund_failed_launch_pad() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        VestParams {
+            launch_pad: PRODUCT_NAME.into(),
+            token_amount: 2000.into(),
+        },
+        Amount::from_ccd(5 * 2000),
+        lp_contract,
+    )?;
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    refund_investment(
+        &mut chain,
+        HOLDERS[0],
+        PRODUCT_NAME.to_string(),
+        lp_contract,
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+
+    assert_eq!(view.raised, Amount::zero());
+    assert_eq!(chain.contract_balance(lp_contract), Some(Amount::zero()));
+
+    Ok(())
+}
+
+/// Verifies that the admin can forcibly cancel a launch pad even after the
+/// soft cap is reached, refunding every holder while the owner's tokens stay
+/// frozen until the admin unfreezes them.
+#[test]
+fn refund_force_canceled_launch_pad() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for holder in HOLDERS[..2].iter() {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2500.into(),
+            },
+            Amount::from_ccd(5 * 2500),
+            lp_contract,
+        )?;
+    }
+
+    // Owner can not cancel once the soft cap is reached
+    assert_eq!(
+        cancel_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract),
+        Err(Error::JobFailed)
+    );
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            LaunchPadKey::from(PRODUCT_NAME),
+            None,
+            "LaunchPad.ForceCancel",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        LaunchPadKey::from(PRODUCT_NAME),
+        None,
+        "LaunchPad.ForceCancel",
+    )?;
+
+    for holder in HOLDERS[..2].iter() {
+        refund_investment(&mut chain, *holder, PRODUCT_NAME.to_string(), lp_contract)?;
+    }
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+
+    assert_eq!(view.status, Status::CANCELED);
+    assert!(view.frozen);
+    assert_eq!(chain.contract_balance(lp_contract), Some(Amount::zero()));
+
+    // Deposited tokens are frozen for review
+    assert_eq!(
+        reclaim_tokens(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract),
+        Err(Error::Frozen)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        LaunchPadKey::from(PRODUCT_NAME),
+        None,
+        "LaunchPad.UnfreezeTokens",
+    )?;
+
+    reclaim_tokens(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    // Allocation share is already paid to the admin on reaching the soft cap
+    let balances = get_token_balance(
+        &mut chain,
+        OWNER,
+        &[
+            (OWNER.into(), OWNER_TOKEN_ID),
+            (ADMIN.into(), OWNER_TOKEN_ID),
+        ],
+        cis2_contract,
+    );
+
+    assert_eq!(balances.0, vec![TokenAmount(9900), TokenAmount(100)]);
+
+    Ok(())
+}
@@ -0,0 +1,402 @@
+use concordium_smart_contract_testing::Chain;
+use concordium_std::{AccountAddress, Amount, ContractAddress};
+
+use crate::{
+    errors::Error,
+    params::{ApprovalParams, LaunchPadKey, RoleParams, VestParams},
+    state::{Role, Status},
+};
+
+use super::{
+    approve_launch_pad, cancel_launch_pad, create_launch_pad, default_create_params,
+    initialize_chain_and_contracts, invest, mint_token, read_contract, setup_live_launch_pad,
+    update_contract, view_launch_pad, view_state, ADMIN, HOLDERS, OWNER, OWNER_TOKEN_ID,
+    OWNER_TOKEN_URL, PLATFORM_REG_FEE, PRODUCT_NAME,
+};
+
+/// A helper function which invokes `GrantRole` or `RevokeRole` in launch pad
+/// to grant or revoke the role of an account.
+fn update_role(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    account: AccountAddress,
+    role: Role,
+    grant: bool,
+    contract: ContractAddress,
+) -> Result<(), Error> {
+    let receive_name = if grant {
+        "LaunchPad.GrantRole"
+    } else {
+        "LaunchPad.RevokeRole"
+    };
+
+    update_contract(
+        chain,
+        contract,
+        invoker,
+        RoleParams { account, role },
+        None,
+        receive_name,
+    )
+}
+
+/// Verifies that an analyst can approve the launch pads while the fee is
+/// still received by the admin, and that the analyst is not allowed to do
+/// anything else.
+#[test]
+fn analyst_approves_launch_pad() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    let analyst = HOLDERS[0];
+
+    assert_eq!(
+        update_role(&mut chain, OWNER, analyst, Role::Analyst, true, lp_contract),
+        Err(Error::UnAuthorized)
+    );
+
+    update_role(&mut chain, ADMIN, analyst, Role::Analyst, true, lp_contract)?;
+
+    assert_eq!(
+        update_role(&mut chain, ADMIN, analyst, Role::Analyst, true, lp_contract),
+        Err(Error::Taken)
+    );
+    assert_eq!(
+        view_state(&mut chain, ADMIN, lp_contract).roles,
+        vec![(analyst, vec![Role::Analyst])]
+    );
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    create_launch_pad(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        default_create_params(cis2_contract),
+    )?;
+
+    let admin_balance = chain.account_balance_available(ADMIN).unwrap();
+
+    approve_launch_pad(
+        &mut chain,
+        analyst,
+        ApprovalParams {
+            launch_pad: PRODUCT_NAME.into(),
+            approve: true,
+        },
+        lp_contract,
+    )?;
+
+    assert_eq!(
+        view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract).status,
+        Status::APPROVED
+    );
+    assert_eq!(
+        chain.account_balance_available(ADMIN).unwrap(),
+        admin_balance + PLATFORM_REG_FEE
+    );
+
+    // Analyst is not allowed to update the platform fee
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            analyst,
+            Amount::from_ccd(20),
+            None,
+            "LaunchPad.UpdateRegisterationFee",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    Ok(())
+}
+
+/// Verifies that only the launch pads in review are approved or rejected,
+/// so that a LIVE or CANCELED launch pad is never overturned and the fee
+/// is not paid out again.
+#[test]
+fn approve_only_in_review() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+    let mut params = default_create_params(cis2_contract);
+    params.product.name = "Product A".to_string();
+    create_launch_pad(&mut chain, lp_contract, OWNER, params)?;
+    cancel_launch_pad(&mut chain, OWNER, "Product A", lp_contract)?;
+
+    let balance = chain.contract_balance(lp_contract);
+
+    for (launch_pad, status) in [
+        (PRODUCT_NAME, Status::LIVE),
+        ("Product A", Status::CANCELED),
+    ] {
+        for approve in [true, false] {
+            assert_eq!(
+                approve_launch_pad(
+                    &mut chain,
+                    ADMIN,
+                    ApprovalParams {
+                        launch_pad: launch_pad.into(),
+                        approve,
+                    },
+                    lp_contract,
+                ),
+                Err(Error::JobFailed)
+            );
+        }
+
+        assert_eq!(
+            view_launch_pad(&mut chain, OWNER, launch_pad, lp_contract).status,
+            status
+        );
+    }
+
+    // Registeration fees are not paid out again
+    assert_eq!(chain.contract_balance(lp_contract), balance);
+
+    Ok(())
+}
+
+/// Verifies that the accounts granted the admin role can forcibly cancel and
+/// unfreeze the launch pads, and that canceling a launch pad in review, by
+/// the owner or by an admin, refunds the registeration fee to the owner.
+#[test]
+fn admin_cancels_in_review() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    let admin = HOLDERS[0];
+    update_role(&mut chain, ADMIN, admin, Role::Admin, true, lp_contract)?;
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+    create_launch_pad(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        default_create_params(cis2_contract),
+    )?;
+    assert_eq!(chain.contract_balance(lp_contract), Some(PLATFORM_REG_FEE));
+
+    cancel_launch_pad(&mut chain, OWNER, PRODUCT_NAME, lp_contract)?;
+    assert_eq!(chain.contract_balance(lp_contract), Some(Amount::zero()));
+
+    let mut params = default_create_params(cis2_contract);
+    params.product.name = "Product A".to_string();
+    create_launch_pad(&mut chain, lp_contract, OWNER, params)?;
+
+    for (invoker, result) in [(HOLDERS[1], Err(Error::UnAuthorized)), (admin, Ok(()))] {
+        assert_eq!(
+            update_contract::<_, ()>(
+                &mut chain,
+                lp_contract,
+                invoker,
+                LaunchPadKey::from("Product A"),
+                None,
+                "LaunchPad.ForceCancel",
+            ),
+            result
+        );
+    }
+    assert_eq!(chain.contract_balance(lp_contract), Some(Amount::zero()));
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        admin,
+        LaunchPadKey::from("Product A"),
+        None,
+        "LaunchPad.UnfreezeTokens",
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, "Product A", lp_contract);
+    assert_eq!(view.status, Status::CANCELED);
+    assert!(!view.frozen);
+
+    Ok(())
+}
+
+/// Verifies that revoked roles are not allowed anymore.
+#[test]
+fn revoke_role() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, _, _) = initialize_chain_and_contracts();
+
+    let treasurer = HOLDERS[1];
+
+    update_role(
+        &mut chain,
+        ADMIN,
+        treasurer,
+        Role::Treasurer,
+        true,
+        lp_contract,
+    )?;
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        treasurer,
+        Amount::from_ccd(20),
+        None,
+        "LaunchPad.UpdateRegisterationFee",
+    )?;
+
+    update_role(
+        &mut chain,
+        ADMIN,
+        treasurer,
+        Role::Treasurer,
+        false,
+        lp_contract,
+    )?;
+
+    assert_eq!(
+        update_role(
+            &mut chain,
+            ADMIN,
+            treasurer,
+            Role::Treasurer,
+            false,
+            lp_contract
+        ),
+        Err(Error::NotFound)
+    );
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            treasurer,
+            Amount::from_ccd(30),
+            None,
+            "LaunchPad.UpdateRegisterationFee",
+        ),
+        Err(Error::UnAuthorized)
+    );
+    assert!(view_state(&mut chain, ADMIN, lp_contract).roles.is_empty());
+
+    Ok(())
+}
+
+/// Verifies that a pauser can freeze every launch pad at once with a reason,
+/// and only the admin can resume the platform afterwards.
+#[test]
+fn pauser_pauses_platform() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    let pauser = HOLDERS[2];
+    let reason = "Exploit in vesting".to_string();
+
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            pauser,
+            reason.clone(),
+            None,
+            "LaunchPad.PausePlatform",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    update_role(&mut chain, ADMIN, pauser, Role::Pauser, true, lp_contract)?;
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        pauser,
+        reason.clone(),
+        None,
+        "LaunchPad.PausePlatform",
+    )?;
+
+    let pause_reason: Option<String> = read_contract(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        (),
+        "LaunchPad.viewPauseReason",
+    );
+    assert_eq!(pause_reason, Some(reason));
+
+    let vest_params = VestParams {
+        launch_pad: PRODUCT_NAME.into(),
+        token_amount: 1000.into(),
+    };
+
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            vest_params.clone(),
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        ),
+        Err(Error::Paused)
+    );
+
+    // Pauser is not allowed to resume the platform
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            pauser,
+            (),
+            None,
+            "LaunchPad.ResumePlatform",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        ADMIN,
+        (),
+        None,
+        "LaunchPad.ResumePlatform",
+    )?;
+
+    assert_eq!(
+        view_state(&mut chain, OWNER, lp_contract).pause_reason,
+        None
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        vest_params,
+        Amount::from_ccd(5 * 1000),
+        lp_contract,
+    )?;
+
+    Ok(())
+}
@@ -2,10 +2,10 @@ use crate::{
     dex::{AddLiquidityParams, ExchangeView, GetExchangeParams, TokenInfo},
     errors::Error,
     params::{
-        ApprovalParams, ClaimLockedParams, ClaimUnLockedParams, Claimer, CreateParams,
+        ApprovalParams, ClaimLockedParams, ClaimUnLockedParams, Claimer, CreateParams, DepositData,
         LockupDetails, VestParams,
     },
-    state::{LiquidityDetails, Product, TimePeriod, VestingLimits},
+    state::{LiquidityDetails, PaymentToken, Product, Status, TimePeriod, VestingLimits},
     tests::{
         claim_locked_tokens, claim_tokens, get_lp_token_balance, get_token_balance, invest,
         withdraw_raised_funds, HOLDERS,
@@ -13,16 +13,17 @@ use crate::{
     CYCLE_DURATION,
 };
 use concordium_cis2::{
-    OperatorUpdate, TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdVec, UpdateOperator,
-    UpdateOperatorParams,
+    AdditionalData, OperatorUpdate, Receiver, TokenAmountU64 as TokenAmount, TokenIdU64,
+    TokenIdVec, Transfer, TransferParams, UpdateOperator, UpdateOperatorParams,
 };
 
-use concordium_std::{Address, Amount, Duration, Timestamp};
+use concordium_std::{to_bytes, Address, Amount, Duration, OwnedEntrypointName, Timestamp};
 
 use super::{
-    approve_launch_pad, create_launch_pad, deposit_tokens, initialize_chain_and_contracts,
-    mint_token, read_contract, update_contract, view_launch_pad, ADMIN, OWNER, OWNER_TOKEN_ID,
-    OWNER_TOKEN_URL,
+    approve_launch_pad, create_launch_pad, default_create_params, deposit_tokens,
+    initialize_chain_and_contracts, invest_with_token, mint_token, product_token_id, read_contract,
+    tick_until, update_contract, view_launch_pad, view_state, ADMIN, OWNER, OWNER_TOKEN_ID,
+    OWNER_TOKEN_URL, PAYMENT_TOKEN_ID, PRODUCT_NAME,
 };
 
 #[test]
@@ -43,7 +44,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
         product: Product {
             name: PRODUCT_NAME.to_string(),
             owner: OWNER,
-            token_id: OWNER_TOKEN_ID,
+            token_id: product_token_id(),
             token_price: Amount::from_ccd(5),
             allocated_tokens: TokenAmount(10000),
             cis2_contract,
@@ -66,6 +67,10 @@ fn launch_pad_smoke() -> Result<(), Error> {
             liquidity_allocation: 40,
             release_cycles: 3,
         },
+        allowlist_end: None,
+        rounds: vec![],
+        payment_token: None,
+        dex: None,
     };
 
     create_launch_pad(&mut chain, lp_contract, OWNER, add_params)?;
@@ -74,7 +79,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
         &mut chain,
         ADMIN,
         ApprovalParams {
-            product_name: PRODUCT_NAME.to_string(),
+            launch_pad: PRODUCT_NAME.into(),
             approve: true,
         },
         lp_contract,
@@ -92,7 +97,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
         &mut chain,
         HOLDERS[0],
         VestParams {
-            product_name: PRODUCT_NAME.to_string(),
+            launch_pad: PRODUCT_NAME.into(),
             token_amount: 1000.into(),
         },
         Amount::from_ccd(5 * 1000),
@@ -103,7 +108,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
         &mut chain,
         HOLDERS[1],
         VestParams {
-            product_name: PRODUCT_NAME.to_string(),
+            launch_pad: PRODUCT_NAME.into(),
             token_amount: 2000.into(),
         },
         Amount::from_ccd(5 * 2000),
@@ -114,7 +119,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
         &mut chain,
         HOLDERS[2],
         VestParams {
-            product_name: PRODUCT_NAME.to_string(),
+            launch_pad: PRODUCT_NAME.into(),
             token_amount: 2200.into(),
         },
         Amount::from_ccd(5 * 2200),
@@ -129,11 +134,18 @@ fn launch_pad_smoke() -> Result<(), Error> {
         "{:?}",
         chain
             .account_balance(OWNER)
-            .and_then(|balance| Some(balance.total.micro_ccd / 1000000))
+            .map(|balance| balance.total.micro_ccd / 1000000)
     );
 
+    let cliff = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)
+        .lock_up
+        .cliff;
+
     for i in 1..=3 {
-        let _ = chain.tick_block_time(Duration::from_millis(3500 + i * CYCLE_DURATION));
+        tick_until(
+            &mut chain,
+            Timestamp::from_timestamp_millis(cliff.millis + i * CYCLE_DURATION),
+        );
 
         for holder in HOLDERS.iter() {
             claim_tokens(
@@ -141,7 +153,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
                 *holder,
                 ClaimUnLockedParams {
                     cycle: i as u8,
-                    product_name: PRODUCT_NAME.to_string(),
+                    launch_pad: PRODUCT_NAME.into(),
                 },
                 lp_contract,
             )?;
@@ -169,7 +181,7 @@ fn launch_pad_smoke() -> Result<(), Error> {
                 *holder,
                 ClaimLockedParams {
                     claimer: Claimer::HOLDER(i),
-                    product_name: PRODUCT_NAME.to_string(),
+                    launch_pad: PRODUCT_NAME.into(),
                 },
                 lp_contract,
             )?;
@@ -191,14 +203,17 @@ fn launch_pad_smoke() -> Result<(), Error> {
     }
 
     for i in 1..=3 {
-        let _ = chain.tick_block_time(Duration::from_millis(3500 + 4 * i * CYCLE_DURATION));
+        tick_until(
+            &mut chain,
+            Timestamp::from_timestamp_millis(cliff.millis + 4 * i * CYCLE_DURATION),
+        );
 
         claim_locked_tokens(
             &mut chain,
             OWNER,
             ClaimLockedParams {
                 claimer: Claimer::OWNER(i as u8),
-                product_name: PRODUCT_NAME.to_string(),
+                launch_pad: PRODUCT_NAME.into(),
             },
             lp_contract,
         )?;
@@ -250,7 +265,7 @@ fn dex_liquid_smoke() -> Result<(), Error> {
 
     let liquidity_params = AddLiquidityParams {
         token: TokenInfo {
-            id: TokenIdVec(OWNER_TOKEN_ID.0.to_le_bytes().into()),
+            id: product_token_id(),
             address: cis2_addr,
         },
         token_amount: 10000.into(),
@@ -268,7 +283,7 @@ fn dex_liquid_smoke() -> Result<(), Error> {
     let exc_params = GetExchangeParams {
         holder: Address::Account(OWNER),
         token: TokenInfo {
-            id: TokenIdVec(OWNER_TOKEN_ID.0.to_le_bytes().into()),
+            id: product_token_id(),
             address: cis2_addr,
         },
     };
@@ -285,3 +300,213 @@ fn dex_liquid_smoke() -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Verifies that the launch pad identifies the product token by its complete
+/// token ID, so that holding a token whose ID only shares the leading byte
+/// with a long token ID does not qualify the owner to list the long one.
+#[test]
+fn launch_pad_long_token_id() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    let mut params = default_create_params(cis2_contract);
+    let mut token_id = vec![OWNER_TOKEN_ID.0];
+    token_id.extend([0u8; 31]);
+    params.product.token_id = TokenIdVec(token_id);
+
+    // Balance query of the unknown token is rejected by the CIS2 contract
+    assert!(create_launch_pad(&mut chain, lp_contract, OWNER, params).is_err());
+
+    assert_eq!(
+        view_state(&mut chain, OWNER, lp_contract).total_launch_pads,
+        0
+    );
+
+    Ok(())
+}
+
+/// Verifies the complete flow of a launch pad whose product token has a
+/// multi-byte token ID. LPTokens of the DEX, identified by `TokenIdU64`, are
+/// listed as the product, while the sale is paid in a CIS2 token so that no
+/// liquidity is added from the raised funds.
+#[test]
+fn launch_pad_multi_byte_token_id() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, dex_contract) = initialize_chain_and_contracts();
+
+    // Owner receives the LPTokens of the pool of its token in the DEX
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        cis2_contract,
+        OWNER,
+        UpdateOperatorParams(vec![UpdateOperator {
+            update: OperatorUpdate::Add,
+            operator: dex_contract.into(),
+        }]),
+        None,
+        "cis2_multi.updateOperator",
+    )?;
+
+    let token = TokenInfo {
+        id: product_token_id(),
+        address: cis2_contract,
+    };
+    update_contract::<_, ()>(
+        &mut chain,
+        dex_contract,
+        OWNER,
+        AddLiquidityParams {
+            token: token.clone(),
+            token_amount: 1000.into(),
+        },
+        Some(Amount::from_ccd(1000)),
+        "pixpel_swap.addLiquidity",
+    )?;
+
+    let lp_token_id = read_contract::<_, ExchangeView>(
+        &mut chain,
+        dex_contract,
+        OWNER,
+        GetExchangeParams {
+            holder: Address::Account(OWNER),
+            token,
+        },
+        "pixpel_swap.getExchange",
+    )
+    .lp_token_id;
+    let token_id = TokenIdVec(lp_token_id.0.to_le_bytes().to_vec());
+
+    let mut params = default_create_params(dex_contract);
+    params.product.token_id = token_id.clone();
+    params.product.token_price = Amount::from_micro_ccd(2);
+    params.soft_cap = Amount::from_micro_ccd(2 * 5000);
+    params.hard_cap = Some(Amount::from_micro_ccd(2 * 7000));
+    params.liquidity_details.liquidity_allocation = 0;
+    params.payment_token = Some(PaymentToken {
+        contract: cis2_contract,
+        token_id: TokenIdVec(vec![PAYMENT_TOKEN_ID.0]),
+    });
+
+    create_launch_pad(&mut chain, lp_contract, OWNER, params)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(view.product.token_id, token_id);
+
+    approve_launch_pad(
+        &mut chain,
+        ADMIN,
+        ApprovalParams {
+            launch_pad: PRODUCT_NAME.into(),
+            approve: true,
+        },
+        lp_contract,
+    )?;
+
+    update_contract::<_, ()>(
+        &mut chain,
+        dex_contract,
+        OWNER,
+        TransferParams(vec![Transfer {
+            token_id: lp_token_id,
+            amount: TokenAmount(10000),
+            from: Address::Account(OWNER),
+            to: Receiver::Contract(
+                lp_contract,
+                OwnedEntrypointName::new_unchecked("Deposit".to_string()),
+            ),
+            data: AdditionalData::from(to_bytes(&DepositData::V2 {
+                launch_pad: PRODUCT_NAME.into(),
+            })),
+        }]),
+        None,
+        "pixpel_swap.transfer",
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(view.status, Status::LIVE);
+
+    for holder in &HOLDERS[..2] {
+        mint_token(
+            &mut chain,
+            *holder,
+            cis2_contract,
+            PAYMENT_TOKEN_ID,
+            OWNER_TOKEN_URL.to_string(),
+        );
+
+        invest_with_token(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2500.into(),
+            },
+            (PAYMENT_TOKEN_ID, TokenAmount(2 * 2500)),
+            cis2_contract,
+            lp_contract,
+        )?;
+    }
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    let cliff = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)
+        .release_schedule
+        .expect("Release schedule is set")
+        .start;
+    tick_until(
+        &mut chain,
+        Timestamp::from_timestamp_millis(cliff.millis + CYCLE_DURATION * 3),
+    );
+
+    // Every cycle released to the holder is claimed in LPTokens of the DEX
+    for cycle in 1..=3 {
+        claim_tokens(
+            &mut chain,
+            HOLDERS[0],
+            ClaimUnLockedParams {
+                cycle,
+                launch_pad: PRODUCT_NAME.into(),
+            },
+            lp_contract,
+        )?;
+    }
+
+    // Platform's allocation of 1% is paid in LPTokens once the soft cap is
+    // reached
+    let balances = get_lp_token_balance(
+        &mut chain,
+        OWNER,
+        &[
+            (HOLDERS[0].into(), lp_token_id),
+            (ADMIN.into(), lp_token_id),
+            (lp_contract.into(), lp_token_id),
+        ],
+        dex_contract,
+    );
+    assert_eq!(
+        balances.0,
+        vec![
+            TokenAmount(2500),
+            TokenAmount(100),
+            TokenAmount(10000 - 100 - 2500)
+        ]
+    );
+
+    Ok(())
+}
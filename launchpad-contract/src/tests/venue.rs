@@ -0,0 +1,578 @@
+use concordium_cis2::{
+    OperatorOfQueryResponse, TokenAmountU64 as TokenAmount, TokenIdU64, TokenIdVec,
+};
+use concordium_std::{
+    test_infrastructure::{
+        MockFn, TestHost, TestLogger, TestReceiveContext, TestStateApi, TestStateBuilder,
+    },
+    AccountAddress, Address, Amount, ContractAddress, HasHost, HasLogger, MetadataUrl,
+    OwnedEntrypointName, Timestamp,
+};
+
+use crate::{
+    dex::{
+        add_bounded_liquidity, AddLiquidityParams, ExchangeView, LiquidityVenue, LpTransfer,
+        TokenInfo,
+    },
+    errors::Error,
+    params::{ClaimLockedParams, Claimer, WithdrawParams},
+    state::{Admin, HolderInfo, LaunchPad, State, Status, STATE_VERSION},
+    withdraw_locked_funds_with, withdraw_raised_with, CYCLE_DURATION,
+};
+
+use super::{
+    default_create_params, ADMIN, ALLOC_SHARE, HOLDERS, LIQUID_SHARE, OWNER, PLATFORM_REG_FEE,
+    PRODUCT_NAME,
+};
+
+/// Address of the launch pad providing the liquidity to the mock venue
+const SELF_ADDRESS: ContractAddress = ContractAddress::new(0, 0);
+
+/// Liquidity pool of a token in the mock venue, along with the LPToken
+/// balances of its holders.
+struct MockPool {
+    token: TokenInfo,
+    token_balance: TokenAmount,
+    ccd_balance: TokenAmount,
+    lp_token_id: TokenIdU64,
+    lp_tokens_supply: TokenAmount,
+    balances: Vec<(Address, TokenAmount)>,
+}
+
+impl MockPool {
+    fn balance_of(&self, holder: &Address) -> TokenAmount {
+        self.balances
+            .iter()
+            .find(|(address, _)| address == holder)
+            .map_or(0.into(), |(_, balance)| *balance)
+    }
+
+    fn credit(&mut self, holder: Address, amount: TokenAmount) {
+        match self
+            .balances
+            .iter_mut()
+            .find(|(address, _)| *address == holder)
+        {
+            Some((_, balance)) => *balance += amount,
+            None => self.balances.push((holder, amount)),
+        }
+    }
+}
+
+/// # Mock Venue
+///
+/// In-process implementation of the `LiquidityVenue`, which keeps the pools
+/// in memory instead of invoking a DEX contract. LPTokens are issued 1:1 for
+/// the CCD of the first liquidity, and pro-rata to the CCD balance of the pool
+/// afterwards.
+pub struct MockVenue {
+    address: ContractAddress,
+    pools: Vec<MockPool>,
+}
+
+impl MockVenue {
+    pub fn new(address: ContractAddress) -> Self {
+        Self {
+            address,
+            pools: Vec::new(),
+        }
+    }
+
+    fn pool(&self, token: &TokenInfo) -> Option<&MockPool> {
+        self.pools
+            .iter()
+            .find(|pool| pool.token.id == token.id && pool.token.address == token.address)
+    }
+}
+
+impl<H> LiquidityVenue<H> for MockVenue {
+    fn address(&self) -> ContractAddress {
+        self.address
+    }
+
+    fn find_pool(
+        &self,
+        _host: &mut H,
+        holder: Address,
+        token: &TokenInfo,
+    ) -> Result<Option<ExchangeView>, Error> {
+        Ok(self.pool(token).map(|pool| ExchangeView {
+            token: pool.token.clone(),
+            token_balance: pool.token_balance,
+            ccd_balance: pool.ccd_balance,
+            lp_token_id: pool.lp_token_id,
+            lp_tokens_supply: pool.lp_tokens_supply,
+            lp_tokens_holder_balance: pool.balance_of(&holder),
+        }))
+    }
+
+    fn add_liquidity(
+        &mut self,
+        _host: &mut H,
+        provider: Address,
+        params: &AddLiquidityParams,
+        amount: Amount,
+    ) -> Result<(), Error> {
+        if self.pool(&params.token).is_none() {
+            let lp_token_id = TokenIdU64(self.pools.len() as u64 + 1);
+            self.pools.push(MockPool {
+                token: params.token.clone(),
+                token_balance: 0.into(),
+                ccd_balance: 0.into(),
+                lp_token_id,
+                lp_tokens_supply: 0.into(),
+                balances: Vec::new(),
+            });
+        }
+
+        let pool = self
+            .pools
+            .iter_mut()
+            .find(|pool| {
+                pool.token.id == params.token.id && pool.token.address == params.token.address
+            })
+            .unwrap();
+        let minted: TokenAmount = if pool.lp_tokens_supply.0 == 0 {
+            amount.micro_ccd.into()
+        } else {
+            (amount.micro_ccd * pool.lp_tokens_supply.0 / pool.ccd_balance.0).into()
+        };
+
+        pool.token_balance += params.token_amount;
+        pool.ccd_balance += amount.micro_ccd.into();
+        pool.lp_tokens_supply += minted;
+        pool.credit(provider, minted);
+
+        Ok(())
+    }
+
+    fn transfer_lp_tokens(
+        &mut self,
+        _host: &mut H,
+        transfers: Vec<LpTransfer>,
+    ) -> Result<(), Error> {
+        for transfer in transfers {
+            let pool = self
+                .pools
+                .iter_mut()
+                .find(|pool| pool.lp_token_id == transfer.token_id)
+                .ok_or(Error::NotFound)?;
+
+            let from = pool
+                .balances
+                .iter_mut()
+                .find(|(address, _)| *address == transfer.from)
+                .ok_or(Error::Insufficient)?;
+            if from.1 < transfer.amount {
+                return Err(Error::Insufficient);
+            }
+            from.1 -= transfer.amount;
+
+            pool.credit(transfer.to.address(), transfer.amount);
+        }
+
+        Ok(())
+    }
+}
+
+/// Address of the CIS2 contract of the product token
+const CIS2_CONTRACT: ContractAddress = ContractAddress::new(1, 0);
+
+/// Address of the mock venue the liquidity is locked in
+const VENUE_ADDRESS: ContractAddress = ContractAddress::new(2, 0);
+
+fn product_token() -> TokenInfo {
+    TokenInfo {
+        id: TokenIdVec(vec![1]),
+        address: CIS2_CONTRACT,
+    }
+}
+
+fn liquidity(token_amount: u64) -> AddLiquidityParams {
+    AddLiquidityParams {
+        token: product_token(),
+        token_amount: token_amount.into(),
+    }
+}
+
+fn bounds(min_lp_tokens: u64, max_price_deviation: u64) -> WithdrawParams {
+    WithdrawParams {
+        launch_pad: PRODUCT_NAME.into(),
+        min_lp_tokens: min_lp_tokens.into(),
+        max_price_deviation,
+    }
+}
+
+/// Verifies that the LPTokens received are bounded by the minimum expected,
+/// and only the increase in the provider's balance is counted as received.
+#[test]
+fn bounded_liquidity_counts_received_lp_tokens() {
+    let mut venue = MockVenue::new(ContractAddress::new(2, 0));
+    let provider = Address::Contract(SELF_ADDRESS);
+    let price = Amount::from_micro_ccd(5);
+
+    assert_eq!(
+        add_bounded_liquidity(
+            &mut venue,
+            &mut (),
+            provider,
+            &liquidity(1000),
+            Amount::from_micro_ccd(5000),
+            price,
+            &bounds(5001, 100),
+        ),
+        Err(Error::Slippage)
+    );
+
+    let mut venue = MockVenue::new(ContractAddress::new(2, 0));
+    let (lp_token_id, received) = add_bounded_liquidity(
+        &mut venue,
+        &mut (),
+        provider,
+        &liquidity(1000),
+        Amount::from_micro_ccd(5000),
+        price,
+        &bounds(5000, 100),
+    )
+    .unwrap();
+    assert_eq!(received, 5000.into());
+
+    // Provider already holding LPTokens of the pool only receives the
+    // LPTokens issued for the liquidity added afterwards
+    let (_, received) = add_bounded_liquidity(
+        &mut venue,
+        &mut (),
+        provider,
+        &liquidity(400),
+        Amount::from_micro_ccd(2000),
+        price,
+        &bounds(2000, 100),
+    )
+    .unwrap();
+    assert_eq!(received, 2000.into());
+
+    let pool = LiquidityVenue::<()>::find_pool(&venue, &mut (), provider, &product_token())
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool.lp_token_id, lp_token_id);
+    assert_eq!(pool.lp_tokens_holder_balance, 7000.into());
+}
+
+/// Verifies that the liquidity is not added to a pool whose price deviates
+/// from the liquidity price beyond the bound, and the pool is left untouched.
+#[test]
+fn bounded_liquidity_rejects_skewed_pool() {
+    let mut venue = MockVenue::new(ContractAddress::new(2, 0));
+    let someone = Address::Contract(ContractAddress::new(3, 0));
+
+    // Pool of the token is created at a price of 1 instead of 5
+    LiquidityVenue::<()>::add_liquidity(
+        &mut venue,
+        &mut (),
+        someone,
+        &liquidity(1000),
+        Amount::from_micro_ccd(1000),
+    )
+    .unwrap();
+
+    assert_eq!(
+        add_bounded_liquidity(
+            &mut venue,
+            &mut (),
+            Address::Contract(SELF_ADDRESS),
+            &liquidity(1000),
+            Amount::from_micro_ccd(5000),
+            Amount::from_micro_ccd(5),
+            &bounds(0, 100),
+        ),
+        Err(Error::PriceDeviation)
+    );
+
+    // Pool close enough to the liquidity price accepts the liquidity
+    assert!(add_bounded_liquidity(
+        &mut venue,
+        &mut (),
+        Address::Contract(SELF_ADDRESS),
+        &liquidity(1000),
+        Amount::from_micro_ccd(1010),
+        Amount::from_micro_ccd(1),
+        &bounds(0, 100),
+    )
+    .is_ok());
+
+    let pool = LiquidityVenue::<()>::find_pool(&venue, &mut (), someone, &product_token())
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool.token_balance, 2000.into());
+}
+
+/// A helper function which sets up the host with a launch pad whose sale is
+/// over, after two holders have invested 12500 CCD each for 2500 tokens,
+/// reaching the soft cap. CIS2 contract of the product token is mocked to
+/// accept the venue as an operator of the launch pad.
+fn finished_launch_pad_host() -> TestHost<State<TestStateApi>> {
+    let mut state_builder = TestStateBuilder::new();
+
+    let (product_name, mut launch_pad) = LaunchPad::from_create_params(
+        default_create_params(CIS2_CONTRACT),
+        0,
+        PLATFORM_REG_FEE,
+        VENUE_ADDRESS,
+        MetadataUrl {
+            url: String::new(),
+            hash: None,
+        },
+        &mut state_builder,
+    );
+    launch_pad.status = Status::LIVE;
+    launch_pad.collected = Amount::from_ccd(25000);
+    launch_pad.sold_tokens = 5000.into();
+    launch_pad.available_tokens = 5000.into();
+    launch_pad.token_balance = 10000.into();
+    for holder in &HOLDERS[..2] {
+        let _ = launch_pad.holders.insert(
+            *holder,
+            HolderInfo {
+                tokens: 2500.into(),
+                invested: Amount::from_ccd(12500),
+                claimed_tokens: 0.into(),
+                claimed_lp_tokens: 0.into(),
+                refunded: false,
+                round_tokens: vec![2500.into()],
+                tokens_settled: false,
+                lp_tokens_settled: false,
+            },
+        );
+    }
+
+    let mut launchpads = state_builder.new_map();
+    let _ = launchpads.insert(0, launch_pad);
+    let mut launchpad_ids = state_builder.new_map();
+    let _ = launchpad_ids.insert(product_name, 0);
+
+    let state = State {
+        version: STATE_VERSION,
+        launchpads,
+        launchpad_ids,
+        investors: state_builder.new_map(),
+        admin: Admin {
+            address: ADMIN,
+            registeration_fee: PLATFORM_REG_FEE,
+            allocation_share: ALLOC_SHARE,
+            liquidity_share: LIQUID_SHARE,
+            dex_address: VENUE_ADDRESS,
+        },
+        pending_admin: None,
+        roles: state_builder.new_map(),
+        counter: 1,
+        pause_reason: None,
+        dexes: vec![VENUE_ADDRESS],
+    };
+
+    let mut host = TestHost::new(state, state_builder);
+    host.set_self_address(SELF_ADDRESS);
+    host.set_self_balance(Amount::from_ccd(25000));
+    host.setup_mock_entrypoint(
+        CIS2_CONTRACT,
+        OwnedEntrypointName::new_unchecked("updateOperator".to_string()),
+        MockFn::returning_ok(()),
+    );
+    host.setup_mock_entrypoint(
+        CIS2_CONTRACT,
+        OwnedEntrypointName::new_unchecked("operatorOf".to_string()),
+        MockFn::new_v1(|_, _, _, _| Ok((false, OperatorOfQueryResponse(vec![true])))),
+    );
+
+    host
+}
+
+/// A helper function which returns the receive context of the given account
+/// invoking the launch pad at the given time.
+fn receive_context<'a>(sender: AccountAddress, millis: u64) -> TestReceiveContext<'a> {
+    let mut ctx = TestReceiveContext::empty();
+    ctx.set_sender(Address::Account(sender));
+    ctx.set_invoker(sender);
+    ctx.set_self_address(SELF_ADDRESS);
+    ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(millis));
+    ctx
+}
+
+/// A helper function which returns the LPTokens of the product token pool
+/// held by the given account in the venue.
+fn lp_balance(
+    venue: &MockVenue,
+    host: &mut TestHost<State<TestStateApi>>,
+    holder: AccountAddress,
+) -> TokenAmount {
+    venue
+        .find_pool(host, Address::Account(holder), &product_token())
+        .unwrap()
+        .unwrap()
+        .lp_tokens_holder_balance
+}
+
+/// Verifies that `WithdrawFunds` locks the liquidity allocation in the venue,
+/// charges the platform's share of the LPTokens and transfers the rest of the
+/// raised funds to the product owner.
+#[test]
+fn withdraw_locks_liquidity_in_venue() {
+    let mut host = finished_launch_pad_host();
+    let mut venue = MockVenue::new(VENUE_ADDRESS);
+    let mut logger = TestLogger::init();
+
+    // Sale is not over yet
+    assert_eq!(
+        withdraw_raised_with(
+            &receive_context(OWNER, 2000),
+            &mut host,
+            &mut logger,
+            bounds(0, 100),
+            &mut venue,
+        ),
+        Err(Error::JobFailed)
+    );
+
+    assert_eq!(
+        withdraw_raised_with(
+            &receive_context(HOLDERS[0], 4000),
+            &mut host,
+            &mut logger,
+            bounds(0, 100),
+            &mut venue,
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    withdraw_raised_with(
+        &receive_context(OWNER, 4000),
+        &mut host,
+        &mut logger,
+        bounds(0, 100),
+        &mut venue,
+    )
+    .unwrap();
+
+    // 40% of the raised funds are locked along with 2000 tokens at 5 CCD
+    let pool = venue
+        .find_pool(&mut host, Address::Contract(SELF_ADDRESS), &product_token())
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool.ccd_balance, Amount::from_ccd(10000).micro_ccd.into());
+    assert_eq!(pool.token_balance, 2000.into());
+    assert_eq!(pool.lp_tokens_holder_balance, 9_800_000_000.into());
+    assert_eq!(lp_balance(&venue, &mut host, ADMIN), 200_000_000.into());
+    assert!(host.transfer_occurred(&OWNER, Amount::from_ccd(15000)));
+
+    let launch_pad = host.state().get_launchpad(PRODUCT_NAME.into()).unwrap();
+    let schedule = launch_pad.release_schedule.clone().unwrap();
+    assert!(launch_pad.withdrawn);
+    assert_eq!(launch_pad.token_balance, 8000.into());
+    assert_eq!(schedule.tokens, 3000.into());
+    assert_eq!(
+        schedule.liquidity,
+        Some((pool.lp_token_id, 4_900_000_000.into()))
+    );
+    drop(launch_pad);
+
+    // Raised funds are only withdrawn once
+    assert_eq!(
+        withdraw_raised_with(
+            &receive_context(OWNER, 4000),
+            &mut host,
+            &mut logger,
+            bounds(0, 100),
+            &mut venue,
+        ),
+        Err(Error::Claimed)
+    );
+}
+
+/// Verifies that `WithDrawLockedFunds` transfers the LPTokens released to
+/// the product owner and the holders out of the venue, once per cycle.
+#[test]
+fn withdraw_locked_funds_from_venue() {
+    let mut host = finished_launch_pad_host();
+    let mut venue = MockVenue::new(VENUE_ADDRESS);
+    let mut logger = TestLogger::init();
+
+    withdraw_raised_with(
+        &receive_context(OWNER, 4000),
+        &mut host,
+        &mut logger,
+        bounds(0, 100),
+        &mut venue,
+    )
+    .unwrap();
+
+    let cliff = host
+        .state()
+        .get_launchpad(PRODUCT_NAME.into())
+        .unwrap()
+        .lock_up
+        .cliff
+        .millis;
+    let claim = |claimer| ClaimLockedParams {
+        launch_pad: PRODUCT_NAME.into(),
+        claimer,
+    };
+
+    // Nothing is claimable during the cliff
+    assert_eq!(
+        withdraw_locked_funds_with(
+            &receive_context(HOLDERS[0], cliff - 1),
+            &mut host,
+            &mut logger,
+            claim(Claimer::HOLDER(1)),
+            &mut venue,
+        ),
+        Err(Error::Cliff)
+    );
+
+    // First cycle of the holders is elapsed, but not the owner's
+    let ctx = receive_context(HOLDERS[0], cliff + CYCLE_DURATION);
+    withdraw_locked_funds_with(
+        &ctx,
+        &mut host,
+        &mut logger,
+        claim(Claimer::HOLDER(1)),
+        &mut venue,
+    )
+    .unwrap();
+    assert_eq!(
+        withdraw_locked_funds_with(
+            &ctx,
+            &mut host,
+            &mut logger,
+            claim(Claimer::HOLDER(1)),
+            &mut venue,
+        ),
+        Err(Error::Claimed)
+    );
+    assert_eq!(
+        withdraw_locked_funds_with(
+            &receive_context(OWNER, cliff + CYCLE_DURATION),
+            &mut host,
+            &mut logger,
+            claim(Claimer::OWNER(1)),
+            &mut venue,
+        ),
+        Err(Error::NotElapsed)
+    );
+
+    withdraw_locked_funds_with(
+        &receive_context(OWNER, cliff + CYCLE_DURATION * 4),
+        &mut host,
+        &mut logger,
+        claim(Claimer::OWNER(1)),
+        &mut venue,
+    )
+    .unwrap();
+
+    // Holder gets a third of its half of the holders' LPTokens, and the owner
+    // a third of the owner's LPTokens
+    assert_eq!(
+        lp_balance(&venue, &mut host, HOLDERS[0]),
+        816_666_666.into()
+    );
+    assert_eq!(lp_balance(&venue, &mut host, OWNER), 1_633_333_333.into());
+}
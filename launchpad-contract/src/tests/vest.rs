@@ -0,0 +1,453 @@
+use concordium_cis2::TokenAmountU64 as TokenAmount;
+use concordium_std::{Amount, Duration, Timestamp};
+
+use crate::{
+    errors::Error,
+    params::{AllowlistParams, RoundParams, VestParams},
+    state::{TimePeriod, VestingLimits},
+};
+
+use super::{
+    create_launch_pad, default_create_params, initialize_chain_and_contracts, invest,
+    setup_live_launch_pad, update_contract, view_launch_pad, withdraw_raised_funds, HOLDERS, OWNER,
+    PRODUCT_NAME,
+};
+
+/// Verifies that the investments exceeding the hard cap are rejected and the
+/// launch pad is finished as soon as the hard cap is reached, allowing the
+/// owner to withdraw before the vesting duration elapses.
+#[test]
+fn vest_hard_cap() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for holder in HOLDERS[..2].iter() {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2500.into(),
+            },
+            Amount::from_ccd(5 * 2500),
+            lp_contract,
+        )?;
+    }
+
+    // Hard cap is 7000 tokens worth of CCD
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[2],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2500.into(),
+            },
+            Amount::from_ccd(5 * 2500),
+            lp_contract,
+        ),
+        Err(Error::HardCap)
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[2],
+        VestParams {
+            launch_pad: PRODUCT_NAME.into(),
+            token_amount: 2000.into(),
+        },
+        Amount::from_ccd(5 * 2000),
+        lp_contract,
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(Some(view.raised), view.hard_cap);
+
+    // Launch pad is finished once the hard cap is reached
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 1000.into(),
+            },
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        ),
+        Err(Error::JobFailed)
+    );
+
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    assert!(view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract).withdrawn);
+
+    Ok(())
+}
+
+/// Verifies that the launch pad is finished once the room left under the hard
+/// cap is below the minimum buy, as nobody can invest anymore.
+#[test]
+fn vest_hard_cap_below_min_buy() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for (holder, token_amount) in HOLDERS[..3].iter().zip([2500, 2500, 1500]) {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: token_amount.into(),
+            },
+            Amount::from_ccd(5 * token_amount),
+            lp_contract,
+        )?;
+    }
+
+    // Only 500 tokens worth of CCD are left under the hard cap, while the
+    // minimum buy is 1000 tokens
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 1000.into(),
+            },
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        ),
+        Err(Error::JobFailed)
+    );
+
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    assert!(view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract).withdrawn);
+
+    Ok(())
+}
+
+/// Verifies that the investment is checked against the product token price,
+/// rejecting underpayments and refunding the excess amount paid.
+#[test]
+fn vest_price_checked() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 1000.into(),
+            },
+            Amount::from_ccd(5 * 1000 - 1),
+            lp_contract,
+        ),
+        Err(Error::Insufficient)
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        VestParams {
+            launch_pad: PRODUCT_NAME.into(),
+            token_amount: 1000.into(),
+        },
+        Amount::from_ccd(5 * 1000 + 7),
+        lp_contract,
+    )?;
+
+    assert_eq!(
+        chain.contract_balance(lp_contract),
+        Some(Amount::from_ccd(5 * 1000))
+    );
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    assert_eq!(view.raised, Amount::from_ccd(5 * 1000));
+    assert_eq!(view.holders[0].1.invested, Amount::from_ccd(5 * 1000));
+
+    // Holder is not allowed to exceed the max vesting limit with
+    // subsequent investments
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2000.into(),
+            },
+            Amount::from_ccd(5 * 2000),
+            lp_contract,
+        ),
+        Err(Error::Limit)
+    );
+
+    Ok(())
+}
+
+/// Verifies that only the allowlisted holders are allowed to vest within their
+/// allocations during the allowlist phase, and everyone is allowed afterwards.
+#[test]
+fn vest_allowlist_phase() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    let mut params = default_create_params(cis2_contract);
+    params.allowlist_end = Some(Timestamp::from_timestamp_millis(2000));
+
+    setup_live_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    let allowlist_params = AllowlistParams {
+        launch_pad: PRODUCT_NAME.into(),
+        allocations: vec![(HOLDERS[0], TokenAmount(1500))],
+    };
+
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            HOLDERS[0],
+            &allowlist_params,
+            None,
+            "LaunchPad.UpdateAllowlist",
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    update_contract::<_, ()>(
+        &mut chain,
+        lp_contract,
+        OWNER,
+        &allowlist_params,
+        None,
+        "LaunchPad.UpdateAllowlist",
+    )?;
+
+    // Holders which are not allowlisted can not vest during the
+    // allowlist phase
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[1],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 1000.into(),
+            },
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        ),
+        Err(Error::UnAuthorized)
+    );
+
+    // Allowlisted holders can only vest within their allocation
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2000.into(),
+            },
+            Amount::from_ccd(5 * 2000),
+            lp_contract,
+        ),
+        Err(Error::Limit)
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        VestParams {
+            launch_pad: PRODUCT_NAME.into(),
+            token_amount: 1500.into(),
+        },
+        Amount::from_ccd(5 * 1500),
+        lp_contract,
+    )?;
+
+    let _ = chain.tick_block_time(Duration::from_millis(2500));
+
+    invest(
+        &mut chain,
+        HOLDERS[1],
+        VestParams {
+            launch_pad: PRODUCT_NAME.into(),
+            token_amount: 1000.into(),
+        },
+        Amount::from_ccd(5 * 1000),
+        lp_contract,
+    )?;
+
+    // Allowlist can not be updated once the phase is over
+    assert_eq!(
+        update_contract::<_, ()>(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            &allowlist_params,
+            None,
+            "LaunchPad.UpdateAllowlist",
+        ),
+        Err(Error::JobFailed)
+    );
+
+    Ok(())
+}
+
+/// Verifies that vesting follows the active sale round, each with its own
+/// price, allocation and limits, and the progress is tracked per round.
+/// Rounds can not be priced lower than the previous round.
+#[test]
+fn vest_sale_rounds() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    let mut params = default_create_params(cis2_contract);
+    params.rounds = vec![
+        RoundParams {
+            timeperiod: TimePeriod {
+                start: Timestamp::from_timestamp_millis(0),
+                end: Timestamp::from_timestamp_millis(1000),
+            },
+            token_price: Amount::from_ccd(4),
+            allocated_tokens: TokenAmount(3000),
+            vest_limits: VestingLimits {
+                min: TokenAmount(1000),
+                max: TokenAmount(2000),
+            },
+        },
+        RoundParams {
+            timeperiod: TimePeriod {
+                start: Timestamp::from_timestamp_millis(1500),
+                end: Timestamp::from_timestamp_millis(3000),
+            },
+            token_price: Amount::from_ccd(5),
+            allocated_tokens: TokenAmount(5000),
+            vest_limits: VestingLimits {
+                min: TokenAmount(1000),
+                max: TokenAmount(2500),
+            },
+        },
+    ];
+
+    // Second round can not be cheaper than the first one
+    let mut decreasing = default_create_params(cis2_contract);
+    decreasing.rounds = params.rounds.clone();
+    decreasing.rounds[1].token_price = Amount::from_ccd(3);
+    assert_eq!(
+        create_launch_pad(&mut chain, lp_contract, OWNER, decreasing),
+        Err(Error::InCorrect)
+    );
+
+    setup_live_launch_pad(&mut chain, lp_contract, cis2_contract, params)?;
+
+    let vest_params = |token_amount: u64| VestParams {
+        launch_pad: PRODUCT_NAME.into(),
+        token_amount: token_amount.into(),
+    };
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        vest_params(2000),
+        Amount::from_ccd(4 * 2000),
+        lp_contract,
+    )?;
+
+    // Holder can not exceed the max limit of the round
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[0],
+            vest_params(1000),
+            Amount::from_ccd(4 * 1000),
+            lp_contract,
+        ),
+        Err(Error::Limit)
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[1],
+        vest_params(1000),
+        Amount::from_ccd(4 * 1000),
+        lp_contract,
+    )?;
+
+    // Round allocation is sold out
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[2],
+            vest_params(1000),
+            Amount::from_ccd(4 * 1000),
+            lp_contract,
+        ),
+        Err(Error::Limit)
+    );
+
+    // No round is active in between the rounds
+    let _ = chain.tick_block_time(Duration::from_millis(1200));
+
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[2],
+            vest_params(1000),
+            Amount::from_ccd(5 * 1000),
+            lp_contract,
+        ),
+        Err(Error::JobFailed)
+    );
+
+    let _ = chain.tick_block_time(Duration::from_millis(400));
+
+    // Paying at the price of the previous round is not enough
+    assert_eq!(
+        invest(
+            &mut chain,
+            HOLDERS[1],
+            vest_params(2500),
+            Amount::from_ccd(4 * 2500),
+            lp_contract,
+        ),
+        Err(Error::Insufficient)
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[1],
+        vest_params(2500),
+        Amount::from_ccd(5 * 2500),
+        lp_contract,
+    )?;
+
+    let launch_pad = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+
+    assert_eq!(launch_pad.rounds[0].sold_tokens, TokenAmount(3000));
+    assert_eq!(launch_pad.rounds[0].collected, Amount::from_ccd(4 * 3000));
+    assert_eq!(launch_pad.rounds[1].sold_tokens, TokenAmount(2500));
+    assert_eq!(launch_pad.rounds[1].collected, Amount::from_ccd(5 * 2500));
+    assert_eq!(launch_pad.raised, Amount::from_ccd(4 * 3000 + 5 * 2500));
+
+    Ok(())
+}
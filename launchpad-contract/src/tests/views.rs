@@ -0,0 +1,330 @@
+use concordium_cis2::TokenAmountU64 as TokenAmount;
+use concordium_smart_contract_testing::Chain;
+use concordium_std::{AccountAddress, Amount, ContractAddress, Duration};
+
+use crate::{
+    errors::Error,
+    params::{ApprovalParams, ClaimUnLockedParams, HolderPositionParams, ListParams, VestParams},
+    response::{HolderPosition, LaunchPadsPage},
+    state::Status,
+};
+
+use super::{
+    approve_launch_pad, claim_tokens, create_launch_pad, default_create_params,
+    initialize_chain_and_contracts, invest, mint_token, read_contract, setup_live_launch_pad,
+    tick_until, update_contract, view_launch_pad, view_state, withdraw_raised_funds, ADMIN,
+    HOLDERS, OWNER, OWNER_TOKEN_ID, OWNER_TOKEN_URL, PRODUCT_NAME,
+};
+
+/// A helper function to invoke `viewLaunchPadsPage` in launch pad to list the
+/// launch pads page by page.
+fn view_launch_pads_page(
+    chain: &mut Chain,
+    params: ListParams,
+    contract: ContractAddress,
+) -> LaunchPadsPage {
+    read_contract(
+        chain,
+        contract,
+        OWNER,
+        params,
+        "LaunchPad.viewLaunchPadsPage",
+    )
+}
+
+/// A helper function to invoke `viewHolderPosition` in launch pad to view the
+/// position of the holder.
+fn view_holder_position(
+    chain: &mut Chain,
+    account: AccountAddress,
+    contract: ContractAddress,
+) -> Result<HolderPosition, Error> {
+    update_contract(
+        chain,
+        contract,
+        OWNER,
+        HolderPositionParams {
+            launch_pad: PRODUCT_NAME.into(),
+            account,
+        },
+        None,
+        "LaunchPad.viewHolderPosition",
+    )
+}
+
+/// Verifies that the launch pads are listed page by page as summaries,
+/// optionally filtered by their status.
+#[test]
+fn view_launch_pads_page_filtered() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    // Owner holds the allocated tokens for the further launch pads,
+    // which are left in review
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+
+    for name in ["Product A", "Product B", "Product C"] {
+        let mut params = default_create_params(cis2_contract);
+        params.product.name = name.to_string();
+        create_launch_pad(&mut chain, lp_contract, OWNER, params)?;
+    }
+
+    let page = view_launch_pads_page(
+        &mut chain,
+        ListParams {
+            from: 0,
+            limit: 3,
+            status: None,
+        },
+        lp_contract,
+    );
+    assert_eq!(page.launch_pads.len(), 3);
+    assert_eq!(page.next_id, Some(3));
+    // Launch pads are listed in the order of their creation
+    assert_eq!(
+        page.launch_pads
+            .iter()
+            .map(|launch_pad| launch_pad.id)
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+
+    let page = view_launch_pads_page(
+        &mut chain,
+        ListParams {
+            from: 3,
+            limit: 3,
+            status: None,
+        },
+        lp_contract,
+    );
+    assert_eq!(page.launch_pads.len(), 1);
+    assert_eq!(page.next_id, None);
+
+    let page = view_launch_pads_page(
+        &mut chain,
+        ListParams {
+            from: 0,
+            limit: 10,
+            status: Some(Status::LIVE),
+        },
+        lp_contract,
+    );
+    assert_eq!(page.launch_pads.len(), 1);
+    assert_eq!(page.launch_pads[0].product.name, PRODUCT_NAME);
+    assert_eq!(page.launch_pads[0].status, Status::LIVE);
+
+    // Filtered pages continue from the next matching launch pad
+    let page = view_launch_pads_page(
+        &mut chain,
+        ListParams {
+            from: 0,
+            limit: 2,
+            status: Some(Status::INREVIEW),
+        },
+        lp_contract,
+    );
+    assert!(page
+        .launch_pads
+        .iter()
+        .all(|launch_pad| launch_pad.status == Status::INREVIEW));
+    assert_eq!(
+        page.launch_pads
+            .iter()
+            .map(|launch_pad| launch_pad.id)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(page.next_id, Some(3));
+
+    let page = view_launch_pads_page(
+        &mut chain,
+        ListParams {
+            from: 3,
+            limit: 2,
+            status: Some(Status::INREVIEW),
+        },
+        lp_contract,
+    );
+    assert_eq!(page.launch_pads.len(), 1);
+    assert_eq!(page.launch_pads[0].id, 3);
+    assert_eq!(page.next_id, None);
+
+    // Page size is limited
+    assert_eq!(
+        update_contract::<_, LaunchPadsPage>(
+            &mut chain,
+            lp_contract,
+            OWNER,
+            ListParams {
+                from: 0,
+                limit: 51,
+                status: None,
+            },
+            None,
+            "LaunchPad.viewLaunchPadsPage",
+        )
+        .map(|page| page.launch_pads.len()),
+        Err(Error::Limit)
+    );
+
+    Ok(())
+}
+
+/// Verifies that the position of any holder can be viewed, with the amounts
+/// claimable and claimed following the release cycles.
+#[test]
+fn view_holder_position_cycles() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    for holder in HOLDERS[..2].iter() {
+        invest(
+            &mut chain,
+            *holder,
+            VestParams {
+                launch_pad: PRODUCT_NAME.into(),
+                token_amount: 2500.into(),
+            },
+            Amount::from_ccd(5 * 2500),
+            lp_contract,
+        )?;
+    }
+
+    assert_eq!(
+        view_holder_position(&mut chain, HOLDERS[2], lp_contract).map(|_| ()),
+        Err(Error::NotFound)
+    );
+
+    let _ = chain.tick_block_time(Duration::from_millis(3500));
+
+    withdraw_raised_funds(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME.to_string(), lp_contract);
+    let schedule = view.release_schedule.expect("Release schedule is set");
+    let (invested, raised) = (Amount::from_ccd(5 * 2500), view.raised);
+    let (bought, sold) = (TokenAmount(2500), view.sold_tokens);
+
+    let position = view_holder_position(&mut chain, HOLDERS[0], lp_contract)?;
+    assert_eq!(position.invested, invested);
+    assert_eq!(position.tokens, 2500.into());
+    assert_eq!(position.claimable_tokens, 0.into());
+    assert_eq!(position.claimable_lp_tokens, 0.into());
+    assert_eq!(position.next_unlock, Some(schedule.cycle_time(1)));
+
+    tick_until(&mut chain, schedule.cycle_time(1));
+
+    let position = view_holder_position(&mut chain, HOLDERS[0], lp_contract)?;
+    assert_eq!(
+        position.claimable_tokens,
+        schedule.released_tokens(bought, sold, 1)
+    );
+    assert_eq!(
+        position.claimable_lp_tokens,
+        schedule.released_lp_tokens(invested, raised, 1)
+    );
+    assert_eq!(position.next_unlock, Some(schedule.cycle_time(2)));
+
+    claim_tokens(
+        &mut chain,
+        HOLDERS[0],
+        ClaimUnLockedParams {
+            cycle: 1,
+            launch_pad: PRODUCT_NAME.into(),
+        },
+        lp_contract,
+    )?;
+
+    let position = view_holder_position(&mut chain, HOLDERS[0], lp_contract)?;
+    assert_eq!(position.claimable_tokens, 0.into());
+    assert_eq!(
+        position.claimed_tokens,
+        schedule.released_tokens(bought, sold, 1)
+    );
+    assert_eq!(position.claimed_lp_tokens, 0.into());
+
+    Ok(())
+}
+
+/// Verifies that the launch pads are assigned sequential IDs at creation,
+/// and are identified either by their IDs or by their product names.
+#[test]
+fn view_launch_pads_by_id() -> Result<(), Error> {
+    let (mut chain, _, lp_contract, cis2_contract, _) = initialize_chain_and_contracts();
+
+    setup_live_launch_pad(
+        &mut chain,
+        lp_contract,
+        cis2_contract,
+        default_create_params(cis2_contract),
+    )?;
+
+    mint_token(
+        &mut chain,
+        OWNER,
+        cis2_contract,
+        OWNER_TOKEN_ID,
+        OWNER_TOKEN_URL.to_string(),
+    );
+    let mut params = default_create_params(cis2_contract);
+    params.product.name = "Product A".to_string();
+    create_launch_pad(&mut chain, lp_contract, OWNER, params)?;
+
+    let view = view_launch_pad(&mut chain, OWNER, "Product A", lp_contract);
+    assert_eq!(view.id, 1);
+    let view = view_launch_pad(&mut chain, OWNER, 1, lp_contract);
+    assert_eq!(view.product.name, "Product A");
+    assert_eq!(view.status, Status::INREVIEW);
+
+    // Unknown ID is not resolved to any launch pad
+    assert_eq!(
+        approve_launch_pad(
+            &mut chain,
+            ADMIN,
+            ApprovalParams {
+                launch_pad: 2.into(),
+                approve: true,
+            },
+            lp_contract,
+        ),
+        Err(Error::NotFound)
+    );
+
+    invest(
+        &mut chain,
+        HOLDERS[0],
+        VestParams {
+            launch_pad: 0.into(),
+            token_amount: 2000.into(),
+        },
+        Amount::from_ccd(5 * 2000),
+        lp_contract,
+    )?;
+
+    let view = view_launch_pad(&mut chain, OWNER, PRODUCT_NAME, lp_contract);
+    assert_eq!(view.holders.len(), 1);
+    assert_eq!(
+        view_state(&mut chain, OWNER, lp_contract).investors,
+        vec![(HOLDERS[0], vec![0])]
+    );
+
+    Ok(())
+}
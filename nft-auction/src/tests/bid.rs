@@ -57,7 +57,7 @@ fn bid_smoke() {
     };
 
     // BOB bids on the item added by ALICE
-    let _ = bid_on_item(
+    bid_on_item(
         &mut chain,
         auction_contract,
         BOB,
@@ -85,7 +85,7 @@ fn bid_smoke() {
     };
 
     // Now CAROL makes the highest bid on the same item added by ALICE
-    let _ = bid_on_item(
+    bid_on_item(
         &mut chain,
         auction_contract,
         CAROL,
@@ -201,7 +201,7 @@ fn bid_not_allowed() {
     };
 
     // Now CAROL makes the highest bid on the item added by ALICE
-    let _ = bid_on_item(
+    bid_on_item(
         &mut chain,
         auction_contract,
         CAROL,
@@ -212,7 +212,7 @@ fn bid_not_allowed() {
     .expect("[Error] Unable to place bid, invocation failed");
 
     // Wrong bid parameters to be tried by BOB
-    let bid_params = vec![
+    let bid_params = [
         (
             BidParams {
                 token_id: TokenID(2u8),
@@ -75,7 +75,7 @@ fn cancel_auction_smoke() {
     };
 
     // BOB bids on the item added by ALICE
-    let _ = bid_on_item(
+    bid_on_item(
         &mut chain,
         auction_contract,
         BOB,
@@ -157,7 +157,7 @@ fn finalize_auction_smoke() {
     let _ = chain.tick_block_time(Duration::from_seconds(10));
 
     // ALICE finalies her auction
-    let _ = finalize_auction(&mut chain, auction_contract, ALICE, ALICE_ADDR, 1)
+    finalize_auction(&mut chain, auction_contract, ALICE, ALICE_ADDR, 1)
         .expect("[Error] Unable to finalize Alice auction, invocation failed");
 
     let alice_cis2_tokens = get_token_balance(&chain, ALICE, cis2_contract, ALICE_TOKEN_ID);
@@ -32,10 +32,7 @@ fn add_item_by_contract() {
     );
 
     assert!(update_result.is_err());
-    assert_eq!(
-        error::Error::OnlyAccount,
-        update_result.err().unwrap().into()
-    )
+    assert_eq!(error::Error::OnlyAccount, update_result.err().unwrap())
 }
 
 /// This testcase is to test negative by trying to invoke `addItem` with expired auction timeline.
@@ -71,7 +68,7 @@ fn add_item_expired() {
     assert!(update_result.is_err());
     assert_eq!(
         error::Error::StartEndTimeError,
-        update_result.err().unwrap().into()
+        update_result.err().unwrap()
     );
 
     // Fast forwarding the chain block time by 1 second
@@ -93,8 +90,5 @@ fn add_item_expired() {
         add_item_for_auction(&mut chain, auction_contract, ALICE, ALICE_ADDR, parameter);
 
     assert!(update_result.is_err());
-    assert_eq!(
-        error::Error::EndTimeError,
-        update_result.err().unwrap().into()
-    );
+    assert_eq!(error::Error::EndTimeError, update_result.err().unwrap());
 }
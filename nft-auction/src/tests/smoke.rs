@@ -62,7 +62,7 @@ fn auction_smoke() {
                     token_id: TokenIdU8(1),
                     creator: ALICE,
                     highest_bid: Amount::from_ccd(10),
-                    cis2_contract: cis2_contract,
+                    cis2_contract,
                     token_amount: TokenAmountU64(1)
                 }
             )],
@@ -60,7 +60,7 @@ fn view_active(_ctx: &ReceiveContext, host: &Host<State>) -> ContractResult<Retu
     // Getting input parameter.
     let inner = get_items(host, AuctionState::NotSoldYet);
 
-    let count = inner.iter().count() as u16;
+    let count = inner.len() as u16;
 
     Ok(ReturnParamView {
         item_states: inner,
@@ -81,7 +81,7 @@ fn view_canceled(_ctx: &ReceiveContext, host: &Host<State>) -> ContractResult<Re
     // Getting input parameter.
     let inner = get_items(host, AuctionState::Canceled);
 
-    let count = inner.iter().count() as u16;
+    let count = inner.len() as u16;
 
     Ok(ReturnParamView {
         item_states: inner,
@@ -111,7 +111,7 @@ fn view_finalized(_ctx: &ReceiveContext, host: &Host<State>) -> ContractResult<R
         .map(|(index, item)| (*index, item.clone()))
         .collect::<Vec<_>>();
 
-    let count = inner.iter().count() as u16;
+    let count = inner.len() as u16;
 
     Ok(ReturnParamView {
         item_states: inner,